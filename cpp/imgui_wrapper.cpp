@@ -3,18 +3,48 @@
 #include "imgui/backends/imgui_impl_glfw.h"
 #include "imgui/backends/imgui_impl_opengl3.h"
 #include <GLFW/glfw3.h>
+#include <string>
+
+// Most ImGui entry points only accept NUL-terminated strings. Ranges passed
+// from Rust are copied into one of these reusable buffers when a terminator
+// is required; slot 0 holds the label and slots 1 and 2 secondary strings.
+static std::string g_scratch[3];
+
+static const char* terminated(int slot, const char* str, const char* str_end) {
+    if (!str) return nullptr;
+    g_scratch[slot].assign(str, str_end);
+    return g_scratch[slot].c_str();
+}
 
 extern "C" {
 
 // Context management
+// CreateContext() keeps an existing current context; the new one is made
+// current so the backends are initialized for it
 void* imgui_create_context(void) {
-    return ImGui::CreateContext();
+    ImGuiContext* ctx = ImGui::CreateContext();
+    ImGui::SetCurrentContext(ctx);
+    return ctx;
 }
 
 void imgui_destroy_context(void* ctx) {
     ImGui::DestroyContext(static_cast<ImGuiContext*>(ctx));
 }
 
+void imgui_set_current_context(void* ctx) {
+    ImGui::SetCurrentContext(static_cast<ImGuiContext*>(ctx));
+}
+
+void* imgui_create_headless_context(float display_width, float display_height) {
+    ImGuiContext* ctx = ImGui::CreateContext();
+    ImGui::SetCurrentContext(ctx);
+    ImGuiIO& io = ImGui::GetIO();
+    io.IniFilename = nullptr;
+    io.DisplaySize = ImVec2(display_width, display_height);
+    io.DeltaTime = 1.0f / 60.0f;
+    return ctx;
+}
+
 // Backend initialization/shutdown
 int imgui_init_for_glfw(GLFWwindow* window, int install_callbacks) {
     return ImGui_ImplGlfw_InitForOpenGL(window, install_callbacks != 0) ? 1 : 0;
@@ -33,9 +63,14 @@ void imgui_shutdown_glfw(void) {
 }
 
 // Frame management
+// Headless contexts have no backends; their display size, time step and
+// input are set through the io functions instead.
 void imgui_new_frame(void) {
-    ImGui_ImplOpenGL3_NewFrame();
-    ImGui_ImplGlfw_NewFrame();
+    ImGuiIO& io = ImGui::GetIO();
+    if (io.BackendRendererUserData)
+        ImGui_ImplOpenGL3_NewFrame();
+    if (io.BackendPlatformUserData)
+        ImGui_ImplGlfw_NewFrame();
     ImGui::NewFrame();
 }
 
@@ -49,7 +84,49 @@ void imgui_end_frame(void) {
 
 // OpenGL3 backend rendering
 void imgui_opengl3_render_draw_data(void) {
-    ImGui_ImplOpenGL3_RenderDrawData(ImGui::GetDrawData());
+    if (ImGui::GetIO().BackendRendererUserData)
+        ImGui_ImplOpenGL3_RenderDrawData(ImGui::GetDrawData());
+}
+
+// Draw data
+static_assert(sizeof(ImDrawVert) == 20, "draw vertices are read as `DrawVert` in src/draw_data.rs");
+static_assert(sizeof(ImDrawIdx) == 2, "draw indices are read as u16");
+
+int imgui_get_draw_data(ImGuiWrapperDrawData* out) {
+    ImDrawData* draw_data = ImGui::GetDrawData();
+    if (!draw_data) return 0;
+    out->display_pos[0] = draw_data->DisplayPos.x;
+    out->display_pos[1] = draw_data->DisplayPos.y;
+    out->display_size[0] = draw_data->DisplaySize.x;
+    out->display_size[1] = draw_data->DisplaySize.y;
+    out->framebuffer_scale[0] = draw_data->FramebufferScale.x;
+    out->framebuffer_scale[1] = draw_data->FramebufferScale.y;
+    out->cmd_lists_count = draw_data->CmdListsCount;
+    out->total_vtx_count = draw_data->TotalVtxCount;
+    out->total_idx_count = draw_data->TotalIdxCount;
+    return 1;
+}
+
+void imgui_get_draw_list(int index, ImGuiWrapperDrawList* out) {
+    const ImDrawList* list = ImGui::GetDrawData()->CmdLists[index];
+    out->vtx_buffer = list->VtxBuffer.Data;
+    out->vtx_count = list->VtxBuffer.Size;
+    out->idx_buffer = list->IdxBuffer.Data;
+    out->idx_count = list->IdxBuffer.Size;
+    out->cmd_count = list->CmdBuffer.Size;
+}
+
+void imgui_get_draw_cmd(int list_index, int cmd_index, ImGuiWrapperDrawCmd* out) {
+    const ImDrawCmd& cmd = ImGui::GetDrawData()->CmdLists[list_index]->CmdBuffer[cmd_index];
+    out->clip_rect[0] = cmd.ClipRect.x;
+    out->clip_rect[1] = cmd.ClipRect.y;
+    out->clip_rect[2] = cmd.ClipRect.z;
+    out->clip_rect[3] = cmd.ClipRect.w;
+    out->texture_id = (uint64_t)cmd.GetTexID();
+    out->vtx_offset = cmd.VtxOffset;
+    out->idx_offset = cmd.IdxOffset;
+    out->elem_count = cmd.ElemCount;
+    out->has_callback = cmd.UserCallback != nullptr ? 1 : 0;
 }
 
 // IO access
@@ -58,6 +135,30 @@ void imgui_io_set_display_size(float width, float height) {
     io.DisplaySize = ImVec2(width, height);
 }
 
+void imgui_io_set_delta_time(float delta_time) {
+    ImGui::GetIO().DeltaTime = delta_time;
+}
+
+void imgui_io_add_mouse_pos_event(float x, float y) {
+    ImGui::GetIO().AddMousePosEvent(x, y);
+}
+
+void imgui_io_add_mouse_button_event(int button, int down) {
+    ImGui::GetIO().AddMouseButtonEvent(button, down != 0);
+}
+
+void imgui_io_add_mouse_wheel_event(float wheel_x, float wheel_y) {
+    ImGui::GetIO().AddMouseWheelEvent(wheel_x, wheel_y);
+}
+
+void imgui_io_add_key_event(int key, int down) {
+    ImGui::GetIO().AddKeyEvent(static_cast<ImGuiKey>(key), down != 0);
+}
+
+void imgui_io_add_input_character(unsigned int c) {
+    ImGui::GetIO().AddInputCharacter(c);
+}
+
 int imgui_io_want_capture_mouse(void) {
     return ImGui::GetIO().WantCaptureMouse ? 1 : 0;
 }
@@ -66,15 +167,21 @@ int imgui_io_want_capture_keyboard(void) {
     return ImGui::GetIO().WantCaptureKeyboard ? 1 : 0;
 }
 
+void imgui_io_display_framebuffer_scale(float out_scale[2]) {
+    ImVec2 scale = ImGui::GetIO().DisplayFramebufferScale;
+    out_scale[0] = scale.x;
+    out_scale[1] = scale.y;
+}
+
 // Basic widgets
-int imgui_begin(const char* name, int* p_open, int flags) {
+int imgui_begin(const char* name, const char* name_end, int* p_open, int flags) {
     bool* open_ptr = nullptr;
     bool open_val;
     if (p_open) {
         open_val = (*p_open != 0);
         open_ptr = &open_val;
     }
-    bool result = ImGui::Begin(name, open_ptr, static_cast<ImGuiWindowFlags>(flags));
+    bool result = ImGui::Begin(terminated(0, name, name_end), open_ptr, static_cast<ImGuiWindowFlags>(flags));
     if (p_open && open_ptr) {
         *p_open = open_val ? 1 : 0;
     }
@@ -85,43 +192,256 @@ void imgui_end(void) {
     ImGui::End();
 }
 
-void imgui_text(const char* text) {
-    ImGui::TextUnformatted(text);
+void imgui_text(const char* text, const char* text_end) {
+    ImGui::TextUnformatted(text, text_end);
+}
+
+// Text is passed through "%.*s", which ImGui prints without copying
+void imgui_text_colored(float r, float g, float b, float a, const char* text, const char* text_end) {
+    ImGui::TextColored(ImVec4(r, g, b, a), "%.*s", static_cast<int>(text_end - text), text);
+}
+
+void imgui_text_disabled(const char* text, const char* text_end) {
+    ImGui::TextDisabled("%.*s", static_cast<int>(text_end - text), text);
+}
+
+void imgui_text_wrapped(const char* text, const char* text_end) {
+    ImGui::TextWrapped("%.*s", static_cast<int>(text_end - text), text);
+}
+
+void imgui_label_text(const char* label, const char* label_end, const char* text, const char* text_end) {
+    ImGui::LabelText(terminated(0, label, label_end), "%.*s", static_cast<int>(text_end - text), text);
+}
+
+void imgui_separator_text(const char* label, const char* label_end) {
+    ImGui::SeparatorText(terminated(0, label, label_end));
+}
+
+void imgui_push_text_wrap_pos(float wrap_local_pos_x) {
+    ImGui::PushTextWrapPos(wrap_local_pos_x);
+}
+
+void imgui_pop_text_wrap_pos(void) {
+    ImGui::PopTextWrapPos();
+}
+
+void imgui_calc_text_size(const char* text, const char* text_end, float out_size[2]) {
+    ImVec2 size = ImGui::CalcTextSize(text, text_end);
+    out_size[0] = size.x;
+    out_size[1] = size.y;
+}
+
+int imgui_button(const char* label, const char* label_end) {
+    return ImGui::Button(terminated(0, label, label_end)) ? 1 : 0;
+}
+
+int imgui_small_button(const char* label, const char* label_end) {
+    return ImGui::SmallButton(terminated(0, label, label_end)) ? 1 : 0;
+}
+
+int imgui_arrow_button(const char* str_id, const char* str_id_end, int dir) {
+    return ImGui::ArrowButton(terminated(0, str_id, str_id_end), static_cast<ImGuiDir>(dir)) ? 1 : 0;
+}
+
+int imgui_invisible_button(const char* str_id, const char* str_id_end, float width, float height, int flags) {
+    return ImGui::InvisibleButton(terminated(0, str_id, str_id_end), ImVec2(width, height),
+                                  static_cast<ImGuiButtonFlags>(flags)) ? 1 : 0;
+}
+
+int imgui_radio_button(const char* label, const char* label_end, int active) {
+    return ImGui::RadioButton(terminated(0, label, label_end), active != 0) ? 1 : 0;
+}
+
+void imgui_progress_bar(float fraction, float width, float height, const char* overlay, const char* overlay_end) {
+    ImGui::ProgressBar(fraction, ImVec2(width, height), terminated(0, overlay, overlay_end));
+}
+
+void imgui_bullet(void) {
+    ImGui::Bullet();
 }
 
-int imgui_button(const char* label) {
-    return ImGui::Button(label) ? 1 : 0;
+void imgui_bullet_text(const char* text, const char* text_end) {
+    ImGui::BulletText("%.*s", static_cast<int>(text_end - text), text);
 }
 
-int imgui_checkbox(const char* label, int* v) {
+int imgui_text_link(const char* label, const char* label_end) {
+    return ImGui::TextLink(terminated(0, label, label_end)) ? 1 : 0;
+}
+
+void imgui_text_link_open_url(const char* label, const char* label_end, const char* url, const char* url_end) {
+    ImGui::TextLinkOpenURL(terminated(0, label, label_end), terminated(1, url, url_end));
+}
+
+int imgui_checkbox(const char* label, const char* label_end, int* v) {
     bool val = (*v != 0);
-    bool result = ImGui::Checkbox(label, &val);
+    bool result = ImGui::Checkbox(terminated(0, label, label_end), &val);
     *v = val ? 1 : 0;
     return result ? 1 : 0;
 }
 
-int imgui_slider_float(const char* label, float* v, float v_min, float v_max) {
-    return ImGui::SliderFloat(label, v, v_min, v_max) ? 1 : 0;
+int imgui_slider_float(const char* label, const char* label_end, float* v, float v_min, float v_max) {
+    return ImGui::SliderFloat(terminated(0, label, label_end), v, v_min, v_max) ? 1 : 0;
+}
+
+int imgui_slider_int(const char* label, const char* label_end, int* v, int v_min, int v_max) {
+    return ImGui::SliderInt(terminated(0, label, label_end), v, v_min, v_max) ? 1 : 0;
+}
+
+int imgui_drag_float(const char* label, const char* label_end, float* v, float v_speed, float v_min, float v_max,
+                     const char* format, const char* format_end, int flags) {
+    return ImGui::DragFloat(terminated(0, label, label_end), v, v_speed, v_min, v_max,
+                            terminated(1, format, format_end), static_cast<ImGuiSliderFlags>(flags)) ? 1 : 0;
+}
+
+int imgui_drag_int(const char* label, const char* label_end, int* v, float v_speed, int v_min, int v_max,
+                   const char* format, const char* format_end, int flags) {
+    return ImGui::DragInt(terminated(0, label, label_end), v, v_speed, v_min, v_max,
+                          terminated(1, format, format_end), static_cast<ImGuiSliderFlags>(flags)) ? 1 : 0;
+}
+
+int imgui_drag_float_range2(const char* label, const char* label_end, float* v_current_min, float* v_current_max,
+                            float v_speed, float v_min, float v_max, const char* format, const char* format_end,
+                            const char* format_max, const char* format_max_end, int flags) {
+    return ImGui::DragFloatRange2(terminated(0, label, label_end), v_current_min, v_current_max, v_speed, v_min, v_max,
+                                  terminated(1, format, format_end), terminated(2, format_max, format_max_end),
+                                  static_cast<ImGuiSliderFlags>(flags)) ? 1 : 0;
+}
+
+int imgui_drag_int_range2(const char* label, const char* label_end, int* v_current_min, int* v_current_max,
+                          float v_speed, int v_min, int v_max, const char* format, const char* format_end,
+                          const char* format_max, const char* format_max_end, int flags) {
+    return ImGui::DragIntRange2(terminated(0, label, label_end), v_current_min, v_current_max, v_speed, v_min, v_max,
+                                terminated(1, format, format_end), terminated(2, format_max, format_max_end),
+                                static_cast<ImGuiSliderFlags>(flags)) ? 1 : 0;
+}
+
+int imgui_slider_scalar_n(const char* label, const char* label_end, int data_type, void* p_data, int components,
+                          const void* p_min, const void* p_max, const char* format, const char* format_end, int flags) {
+    const char* l = terminated(0, label, label_end);
+    const char* f = terminated(1, format, format_end);
+    ImGuiSliderFlags slider_flags = static_cast<ImGuiSliderFlags>(flags);
+    bool result = components == 1
+        ? ImGui::SliderScalar(l, data_type, p_data, p_min, p_max, f, slider_flags)
+        : ImGui::SliderScalarN(l, data_type, p_data, components, p_min, p_max, f, slider_flags);
+    return result ? 1 : 0;
+}
+
+int imgui_v_slider_scalar(const char* label, const char* label_end, float width, float height, int data_type,
+                          void* p_data, const void* p_min, const void* p_max, const char* format, const char* format_end,
+                          int flags) {
+    return ImGui::VSliderScalar(terminated(0, label, label_end), ImVec2(width, height), data_type, p_data, p_min, p_max,
+                                terminated(1, format, format_end), static_cast<ImGuiSliderFlags>(flags)) ? 1 : 0;
 }
 
-int imgui_slider_int(const char* label, int* v, int v_min, int v_max) {
-    return ImGui::SliderInt(label, v, v_min, v_max) ? 1 : 0;
+int imgui_slider_angle(const char* label, const char* label_end, float* v_rad, float v_degrees_min, float v_degrees_max,
+                       const char* format, const char* format_end, int flags) {
+    return ImGui::SliderAngle(terminated(0, label, label_end), v_rad, v_degrees_min, v_degrees_max,
+                              terminated(1, format, format_end), static_cast<ImGuiSliderFlags>(flags)) ? 1 : 0;
 }
 
-int imgui_input_float(const char* label, float* v) {
-    return ImGui::InputFloat(label, v) ? 1 : 0;
+int imgui_drag_scalar_n(const char* label, const char* label_end, int data_type, void* p_data, int components,
+                        float v_speed, const void* p_min, const void* p_max, const char* format, const char* format_end,
+                        int flags) {
+    const char* l = terminated(0, label, label_end);
+    const char* f = terminated(1, format, format_end);
+    ImGuiSliderFlags slider_flags = static_cast<ImGuiSliderFlags>(flags);
+    bool result = components == 1
+        ? ImGui::DragScalar(l, data_type, p_data, v_speed, p_min, p_max, f, slider_flags)
+        : ImGui::DragScalarN(l, data_type, p_data, components, v_speed, p_min, p_max, f, slider_flags);
+    return result ? 1 : 0;
 }
 
-int imgui_input_int(const char* label, int* v) {
-    return ImGui::InputInt(label, v) ? 1 : 0;
+int imgui_input_scalar_n(const char* label, const char* label_end, int data_type, void* p_data, int components,
+                         const void* p_step, const void* p_step_fast, const char* format, const char* format_end,
+                         int flags) {
+    const char* l = terminated(0, label, label_end);
+    const char* f = terminated(1, format, format_end);
+    ImGuiInputTextFlags input_flags = static_cast<ImGuiInputTextFlags>(flags);
+    bool result = components == 1
+        ? ImGui::InputScalar(l, data_type, p_data, p_step, p_step_fast, f, input_flags)
+        : ImGui::InputScalarN(l, data_type, p_data, components, p_step, p_step_fast, f, input_flags);
+    return result ? 1 : 0;
 }
 
-int imgui_color_edit3(const char* label, float col[3]) {
-    return ImGui::ColorEdit3(label, col) ? 1 : 0;
+int imgui_input_float(const char* label, const char* label_end, float* v) {
+    return ImGui::InputFloat(terminated(0, label, label_end), v) ? 1 : 0;
 }
 
-int imgui_color_edit4(const char* label, float col[4]) {
-    return ImGui::ColorEdit4(label, col) ? 1 : 0;
+int imgui_input_int(const char* label, const char* label_end, int* v) {
+    return ImGui::InputInt(terminated(0, label, label_end), v) ? 1 : 0;
+}
+
+static_assert(sizeof(ImGuiInputTextCallbackData) == (sizeof(void*) == 8 ? 64 : 52),
+              "ImGuiInputTextCallbackData layout changed, update src/input_text.rs");
+
+int imgui_input_text(const char* label, const char* label_end, char* buf, size_t buf_size, int flags,
+                     ImGuiWrapperInputTextCallback callback, void* user_data) {
+    return ImGui::InputText(terminated(0, label, label_end), buf, buf_size,
+                            static_cast<ImGuiInputTextFlags>(flags), callback, user_data) ? 1 : 0;
+}
+
+int imgui_input_text_multiline(const char* label, const char* label_end, char* buf, size_t buf_size,
+                               float width, float height, int flags,
+                               ImGuiWrapperInputTextCallback callback, void* user_data) {
+    return ImGui::InputTextMultiline(terminated(0, label, label_end), buf, buf_size, ImVec2(width, height),
+                                     static_cast<ImGuiInputTextFlags>(flags), callback, user_data) ? 1 : 0;
+}
+
+int imgui_input_text_with_hint(const char* label, const char* label_end, const char* hint, const char* hint_end,
+                               char* buf, size_t buf_size, int flags,
+                               ImGuiWrapperInputTextCallback callback, void* user_data) {
+    return ImGui::InputTextWithHint(terminated(0, label, label_end), terminated(1, hint, hint_end), buf, buf_size,
+                                    static_cast<ImGuiInputTextFlags>(flags), callback, user_data) ? 1 : 0;
+}
+
+void imgui_input_text_callback_insert_chars(ImGuiInputTextCallbackData* data, int pos, const char* text, const char* text_end) {
+    data->InsertChars(pos, text, text_end);
+}
+
+void imgui_input_text_callback_delete_chars(ImGuiInputTextCallbackData* data, int pos, int bytes_count) {
+    data->DeleteChars(pos, bytes_count);
+}
+
+// Images
+void imgui_image(uint64_t texture_id, const float size[2], const float uv0[2], const float uv1[2],
+                 const float tint_col[4], const float border_col[4]) {
+    ImGui::Image(static_cast<ImTextureID>(texture_id), ImVec2(size[0], size[1]), ImVec2(uv0[0], uv0[1]),
+                 ImVec2(uv1[0], uv1[1]), ImVec4(tint_col[0], tint_col[1], tint_col[2], tint_col[3]),
+                 ImVec4(border_col[0], border_col[1], border_col[2], border_col[3]));
+}
+
+int imgui_image_button(const char* str_id, const char* str_id_end, uint64_t texture_id, const float size[2],
+                       const float uv0[2], const float uv1[2], const float bg_col[4], const float tint_col[4]) {
+    return ImGui::ImageButton(terminated(0, str_id, str_id_end), static_cast<ImTextureID>(texture_id),
+                              ImVec2(size[0], size[1]), ImVec2(uv0[0], uv0[1]), ImVec2(uv1[0], uv1[1]),
+                              ImVec4(bg_col[0], bg_col[1], bg_col[2], bg_col[3]),
+                              ImVec4(tint_col[0], tint_col[1], tint_col[2], tint_col[3])) ? 1 : 0;
+}
+
+int imgui_color_edit3(const char* label, const char* label_end, float col[3], int flags) {
+    return ImGui::ColorEdit3(terminated(0, label, label_end), col, static_cast<ImGuiColorEditFlags>(flags)) ? 1 : 0;
+}
+
+int imgui_color_edit4(const char* label, const char* label_end, float col[4], int flags) {
+    return ImGui::ColorEdit4(terminated(0, label, label_end), col, static_cast<ImGuiColorEditFlags>(flags)) ? 1 : 0;
+}
+
+int imgui_color_picker3(const char* label, const char* label_end, float col[3], int flags) {
+    return ImGui::ColorPicker3(terminated(0, label, label_end), col, static_cast<ImGuiColorEditFlags>(flags)) ? 1 : 0;
+}
+
+int imgui_color_picker4(const char* label, const char* label_end, float col[4], int flags, const float* ref_col) {
+    return ImGui::ColorPicker4(terminated(0, label, label_end), col, static_cast<ImGuiColorEditFlags>(flags), ref_col) ? 1 : 0;
+}
+
+int imgui_color_button(const char* desc_id, const char* desc_id_end, const float col[4], int flags, float width, float height) {
+    ImVec4 color(col[0], col[1], col[2], col[3]);
+    return ImGui::ColorButton(terminated(0, desc_id, desc_id_end), color, static_cast<ImGuiColorEditFlags>(flags),
+                              ImVec2(width, height)) ? 1 : 0;
+}
+
+void imgui_set_color_edit_options(int flags) {
+    ImGui::SetColorEditOptions(static_cast<ImGuiColorEditFlags>(flags));
 }
 
 void imgui_same_line(void) {
@@ -149,8 +469,8 @@ void imgui_unindent(float indent_w) {
 }
 
 // Tree nodes
-int imgui_tree_node(const char* label) {
-    return ImGui::TreeNode(label) ? 1 : 0;
+int imgui_tree_node(const char* label, const char* label_end) {
+    return ImGui::TreeNode(terminated(0, label, label_end)) ? 1 : 0;
 }
 
 void imgui_tree_pop(void) {
@@ -158,16 +478,16 @@ void imgui_tree_pop(void) {
 }
 
 // Combo box
-int imgui_begin_combo(const char* label, const char* preview_value, int flags) {
-    return ImGui::BeginCombo(label, preview_value, static_cast<ImGuiComboFlags>(flags)) ? 1 : 0;
+int imgui_begin_combo(const char* label, const char* label_end, const char* preview_value, const char* preview_value_end, int flags) {
+    return ImGui::BeginCombo(terminated(0, label, label_end), terminated(1, preview_value, preview_value_end), static_cast<ImGuiComboFlags>(flags)) ? 1 : 0;
 }
 
 void imgui_end_combo(void) {
     ImGui::EndCombo();
 }
 
-int imgui_selectable(const char* label, int selected, int flags) {
-    return ImGui::Selectable(label, selected != 0, static_cast<ImGuiSelectableFlags>(flags)) ? 1 : 0;
+int imgui_selectable(const char* label, const char* label_end, int selected, int flags) {
+    return ImGui::Selectable(terminated(0, label, label_end), selected != 0, static_cast<ImGuiSelectableFlags>(flags)) ? 1 : 0;
 }
 
 // Menu
@@ -179,21 +499,21 @@ void imgui_end_main_menu_bar(void) {
     ImGui::EndMainMenuBar();
 }
 
-int imgui_begin_menu(const char* label, int enabled) {
-    return ImGui::BeginMenu(label, enabled != 0) ? 1 : 0;
+int imgui_begin_menu(const char* label, const char* label_end, int enabled) {
+    return ImGui::BeginMenu(terminated(0, label, label_end), enabled != 0) ? 1 : 0;
 }
 
 void imgui_end_menu(void) {
     ImGui::EndMenu();
 }
 
-int imgui_menu_item(const char* label, const char* shortcut, int selected, int enabled) {
-    return ImGui::MenuItem(label, shortcut, selected != 0, enabled != 0) ? 1 : 0;
+int imgui_menu_item(const char* label, const char* label_end, const char* shortcut, const char* shortcut_end, int selected, int enabled) {
+    return ImGui::MenuItem(terminated(0, label, label_end), terminated(1, shortcut, shortcut_end), selected != 0, enabled != 0) ? 1 : 0;
 }
 
 // Tooltips
-void imgui_set_tooltip(const char* text) {
-    ImGui::SetTooltip("%s", text);
+void imgui_set_tooltip(const char* text, const char* text_end) {
+    ImGui::SetTooltip("%.*s", (int)(text_end - text), text);
 }
 
 int imgui_begin_tooltip(void) {
@@ -205,18 +525,18 @@ void imgui_end_tooltip(void) {
 }
 
 // Popups
-int imgui_begin_popup(const char* str_id, int flags) {
-    return ImGui::BeginPopup(str_id, static_cast<ImGuiWindowFlags>(flags)) ? 1 : 0;
+int imgui_begin_popup(const char* str_id, const char* str_id_end, int flags) {
+    return ImGui::BeginPopup(terminated(0, str_id, str_id_end), static_cast<ImGuiWindowFlags>(flags)) ? 1 : 0;
 }
 
-int imgui_begin_popup_modal(const char* name, int* p_open, int flags) {
+int imgui_begin_popup_modal(const char* name, const char* name_end, int* p_open, int flags) {
     bool* open_ptr = nullptr;
     bool open_val;
     if (p_open) {
         open_val = (*p_open != 0);
         open_ptr = &open_val;
     }
-    bool result = ImGui::BeginPopupModal(name, open_ptr, static_cast<ImGuiWindowFlags>(flags));
+    bool result = ImGui::BeginPopupModal(terminated(0, name, name_end), open_ptr, static_cast<ImGuiWindowFlags>(flags));
     if (p_open && open_ptr) {
         *p_open = open_val ? 1 : 0;
     }
@@ -227,8 +547,8 @@ void imgui_end_popup(void) {
     ImGui::EndPopup();
 }
 
-void imgui_open_popup(const char* str_id) {
-    ImGui::OpenPopup(str_id);
+void imgui_open_popup(const char* str_id, const char* str_id_end) {
+    ImGui::OpenPopup(ImGui::GetID(str_id, str_id_end));
 }
 
 void imgui_close_current_popup(void) {
@@ -236,8 +556,8 @@ void imgui_close_current_popup(void) {
 }
 
 // Tables
-int imgui_begin_table(const char* str_id, int column, int flags) {
-    return ImGui::BeginTable(str_id, column, static_cast<ImGuiTableFlags>(flags)) ? 1 : 0;
+int imgui_begin_table(const char* str_id, const char* str_id_end, int column, int flags) {
+    return ImGui::BeginTable(terminated(0, str_id, str_id_end), column, static_cast<ImGuiTableFlags>(flags)) ? 1 : 0;
 }
 
 void imgui_end_table(void) {
@@ -256,8 +576,8 @@ int imgui_table_set_column_index(int column_n) {
     return ImGui::TableSetColumnIndex(column_n) ? 1 : 0;
 }
 
-void imgui_table_setup_column(const char* label, int flags, float init_width_or_weight) {
-    ImGui::TableSetupColumn(label, static_cast<ImGuiTableColumnFlags>(flags), init_width_or_weight);
+void imgui_table_setup_column(const char* label, const char* label_end, int flags, float init_width_or_weight) {
+    ImGui::TableSetupColumn(terminated(0, label, label_end), static_cast<ImGuiTableColumnFlags>(flags), init_width_or_weight);
 }
 
 void imgui_table_headers_row(void) {
@@ -265,8 +585,8 @@ void imgui_table_headers_row(void) {
 }
 
 // Columns (legacy)
-void imgui_columns(int count, const char* id, int border) {
-    ImGui::Columns(count, id, border != 0);
+void imgui_columns(int count, const char* id, const char* id_end, int border) {
+    ImGui::Columns(count, terminated(0, id, id_end), border != 0);
 }
 
 void imgui_next_column(void) {
@@ -294,13 +614,45 @@ void imgui_pop_style_var(int count) {
     ImGui::PopStyleVar(count);
 }
 
+// Persistent style. The Rust `Style` struct mirrors ImGuiStyle field for
+// field, so the whole struct is copied in one go.
+static_assert(sizeof(ImGuiStyle) == 1156, "ImGuiStyle layout changed, update src/style.rs");
+
+void imgui_get_style(ImGuiStyle* out) {
+    *out = ImGui::GetStyle();
+}
+
+void imgui_set_style(const ImGuiStyle* style) {
+    ImGui::GetStyle() = *style;
+}
+
+void imgui_style_default(ImGuiStyle* out) {
+    *out = ImGuiStyle();
+}
+
+void imgui_style_colors_dark(ImGuiStyle* dst) {
+    ImGui::StyleColorsDark(dst);
+}
+
+void imgui_style_colors_light(ImGuiStyle* dst) {
+    ImGui::StyleColorsLight(dst);
+}
+
+void imgui_style_colors_classic(ImGuiStyle* dst) {
+    ImGui::StyleColorsClassic(dst);
+}
+
+void imgui_style_scale_all_sizes(ImGuiStyle* style, float factor) {
+    style->ScaleAllSizes(factor);
+}
+
 // ID stack
 void imgui_push_id_int(int int_id) {
     ImGui::PushID(int_id);
 }
 
-void imgui_push_id_str(const char* str_id) {
-    ImGui::PushID(str_id);
+void imgui_push_id_str(const char* str_id, const char* str_id_end) {
+    ImGui::PushID(str_id, str_id_end);
 }
 
 void imgui_pop_id(void) {
@@ -320,6 +672,24 @@ int imgui_is_item_active(void) {
     return ImGui::IsItemActive() ? 1 : 0;
 }
 
+void imgui_get_content_region_avail(float out_size[2]) {
+    ImVec2 size = ImGui::GetContentRegionAvail();
+    out_size[0] = size.x;
+    out_size[1] = size.y;
+}
+
+void imgui_get_cursor_screen_pos(float out_pos[2]) {
+    ImVec2 pos = ImGui::GetCursorScreenPos();
+    out_pos[0] = pos.x;
+    out_pos[1] = pos.y;
+}
+
+void imgui_get_mouse_pos(float out_pos[2]) {
+    ImVec2 pos = ImGui::GetMousePos();
+    out_pos[0] = pos.x;
+    out_pos[1] = pos.y;
+}
+
 void imgui_set_next_window_pos(float x, float y, int cond) {
     ImGui::SetNextWindowPos(ImVec2(x, y), static_cast<ImGuiCond>(cond));
 }
@@ -328,6 +698,90 @@ void imgui_set_next_window_size(float width, float height, int cond) {
     ImGui::SetNextWindowSize(ImVec2(width, height), static_cast<ImGuiCond>(cond));
 }
 
+// Fonts
+static_assert(sizeof(ImWchar) == sizeof(unsigned short), "glyph ranges are passed as 16-bit ImWchar");
+
+static ImFontConfig font_config(float size_pixels, const ImGuiWrapperFontConfig* config) {
+    ImFontConfig out;
+    out.FontDataOwnedByAtlas = false;
+    out.SizePixels = size_pixels;
+    if (config) {
+        out.MergeMode = config->merge_mode != 0;
+        out.PixelSnapH = config->pixel_snap_h != 0;
+        out.OversampleH = config->oversample_h;
+        out.OversampleV = config->oversample_v;
+        out.GlyphOffset = ImVec2(config->glyph_offset_x, config->glyph_offset_y);
+        out.GlyphExtraSpacing = ImVec2(config->glyph_extra_spacing_x, 0.0f);
+        out.GlyphMinAdvanceX = config->glyph_min_advance_x;
+        out.GlyphMaxAdvanceX = config->glyph_max_advance_x;
+        out.RasterizerMultiply = config->rasterizer_multiply;
+        out.GlyphRanges = config->glyph_ranges;
+    }
+    return out;
+}
+
+void imgui_fonts_clear(void) {
+    ImGui::GetIO().Fonts->Clear();
+}
+
+int imgui_fonts_add_default(float size_pixels, const ImGuiWrapperFontConfig* config) {
+    ImFontConfig cfg = font_config(size_pixels, config);
+    if (!config) {
+        // What AddFontDefault() uses when no config is given
+        cfg.OversampleH = cfg.OversampleV = 1;
+        cfg.PixelSnapH = true;
+    }
+    ImFont* font = ImGui::GetIO().Fonts->AddFontDefault(&cfg);
+    return font ? ImGui::GetIO().Fonts->Fonts.Size - 1 : -1;
+}
+
+int imgui_fonts_add_ttf(const void* data, int data_size, float size_pixels, const ImGuiWrapperFontConfig* config) {
+    ImFontConfig cfg = font_config(size_pixels, config);
+    ImFont* font = ImGui::GetIO().Fonts->AddFontFromMemoryTTF(
+        const_cast<void*>(data), data_size, size_pixels, &cfg);
+    return font ? ImGui::GetIO().Fonts->Fonts.Size - 1 : -1;
+}
+
+// The built-in ranges are static arrays and do not depend on the atlas
+const unsigned short* imgui_glyph_ranges(int set) {
+    ImFontAtlas atlas;
+    switch (set) {
+        case 1: return atlas.GetGlyphRangesGreek();
+        case 2: return atlas.GetGlyphRangesKorean();
+        case 3: return atlas.GetGlyphRangesJapanese();
+        case 4: return atlas.GetGlyphRangesChineseFull();
+        case 5: return atlas.GetGlyphRangesChineseSimplifiedCommon();
+        case 6: return atlas.GetGlyphRangesCyrillic();
+        case 7: return atlas.GetGlyphRangesThai();
+        case 8: return atlas.GetGlyphRangesVietnamese();
+        default: return atlas.GetGlyphRangesDefault();
+    }
+}
+
+int imgui_fonts_build(void) {
+    // The OpenGL3 backend uploads the texture again on the next NewFrame
+    if (ImGui::GetIO().BackendRendererUserData)
+        ImGui_ImplOpenGL3_DestroyFontsTexture();
+    return ImGui::GetIO().Fonts->Build() ? 1 : 0;
+}
+
+uint64_t imgui_fonts_tex_data_rgba32(const unsigned char** out_pixels, int* out_width, int* out_height) {
+    ImFontAtlas* atlas = ImGui::GetIO().Fonts;
+    unsigned char* pixels = nullptr;
+    atlas->GetTexDataAsRGBA32(&pixels, out_width, out_height);
+    *out_pixels = pixels;
+    return (uint64_t)atlas->TexID;
+}
+
+void imgui_push_font(int index) {
+    ImFontAtlas* atlas = ImGui::GetIO().Fonts;
+    ImGui::PushFont(index >= 0 && index < atlas->Fonts.Size ? atlas->Fonts[index] : nullptr);
+}
+
+void imgui_pop_font(void) {
+    ImGui::PopFont();
+}
+
 // Demo window
 void imgui_show_demo_window(int* p_open) {
     bool* open_ptr = nullptr;
@@ -342,41 +796,186 @@ void imgui_show_demo_window(int* p_open) {
     }
 }
 
-// DPI scaling for Windows high-DPI displays
+// Offscreen render targets. Framebuffer objects are not part of OpenGL 1.1,
+// so their entry points are loaded through GLFW on first use.
+#ifndef GL_FRAMEBUFFER
+#define GL_FRAMEBUFFER 0x8D40
+#endif
+#ifndef GL_FRAMEBUFFER_BINDING
+#define GL_FRAMEBUFFER_BINDING 0x8CA6
+#endif
+#ifndef GL_FRAMEBUFFER_COMPLETE
+#define GL_FRAMEBUFFER_COMPLETE 0x8CD5
+#endif
+#ifndef GL_COLOR_ATTACHMENT0
+#define GL_COLOR_ATTACHMENT0 0x8CE0
+#endif
+#ifndef GL_CLAMP_TO_EDGE
+#define GL_CLAMP_TO_EDGE 0x812F
+#endif
+
+typedef void (APIENTRY* GenFramebuffersFn)(GLsizei n, GLuint* framebuffers);
+typedef void (APIENTRY* DeleteFramebuffersFn)(GLsizei n, const GLuint* framebuffers);
+typedef void (APIENTRY* BindFramebufferFn)(GLenum target, GLuint framebuffer);
+typedef void (APIENTRY* FramebufferTexture2DFn)(GLenum target, GLenum attachment, GLenum textarget, GLuint texture,
+                                                GLint level);
+typedef GLenum (APIENTRY* CheckFramebufferStatusFn)(GLenum target);
+
+static struct {
+    GenFramebuffersFn gen_framebuffers;
+    DeleteFramebuffersFn delete_framebuffers;
+    BindFramebufferFn bind_framebuffer;
+    FramebufferTexture2DFn framebuffer_texture_2d;
+    CheckFramebufferStatusFn check_framebuffer_status;
+} g_fbo;
+
+static bool load_fbo_functions() {
+    if (g_fbo.gen_framebuffers) return true;
+    g_fbo.delete_framebuffers = (DeleteFramebuffersFn)glfwGetProcAddress("glDeleteFramebuffers");
+    g_fbo.bind_framebuffer = (BindFramebufferFn)glfwGetProcAddress("glBindFramebuffer");
+    g_fbo.framebuffer_texture_2d = (FramebufferTexture2DFn)glfwGetProcAddress("glFramebufferTexture2D");
+    g_fbo.check_framebuffer_status = (CheckFramebufferStatusFn)glfwGetProcAddress("glCheckFramebufferStatus");
+    if (!g_fbo.delete_framebuffers || !g_fbo.bind_framebuffer || !g_fbo.framebuffer_texture_2d ||
+        !g_fbo.check_framebuffer_status) {
+        return false;
+    }
+    // Set last, it marks the table as loaded
+    g_fbo.gen_framebuffers = (GenFramebuffersFn)glfwGetProcAddress("glGenFramebuffers");
+    return g_fbo.gen_framebuffers != nullptr;
+}
+
+unsigned int imgui_render_target_create(int width, int height, unsigned int* out_texture) {
+    if (width <= 0 || height <= 0 || !load_fbo_functions()) return 0;
+
+    GLint previous_texture, previous_fbo;
+    glGetIntegerv(GL_TEXTURE_BINDING_2D, &previous_texture);
+    glGetIntegerv(GL_FRAMEBUFFER_BINDING, &previous_fbo);
+
+    GLuint texture;
+    glGenTextures(1, &texture);
+    glBindTexture(GL_TEXTURE_2D, texture);
+    glTexParameteri(GL_TEXTURE_2D, GL_TEXTURE_MIN_FILTER, GL_LINEAR);
+    glTexParameteri(GL_TEXTURE_2D, GL_TEXTURE_MAG_FILTER, GL_LINEAR);
+    glTexParameteri(GL_TEXTURE_2D, GL_TEXTURE_WRAP_S, GL_CLAMP_TO_EDGE);
+    glTexParameteri(GL_TEXTURE_2D, GL_TEXTURE_WRAP_T, GL_CLAMP_TO_EDGE);
+    glTexImage2D(GL_TEXTURE_2D, 0, GL_RGBA8, width, height, 0, GL_RGBA, GL_UNSIGNED_BYTE, nullptr);
+
+    GLuint fbo;
+    g_fbo.gen_framebuffers(1, &fbo);
+    g_fbo.bind_framebuffer(GL_FRAMEBUFFER, fbo);
+    g_fbo.framebuffer_texture_2d(GL_FRAMEBUFFER, GL_COLOR_ATTACHMENT0, GL_TEXTURE_2D, texture, 0);
+    bool complete = g_fbo.check_framebuffer_status(GL_FRAMEBUFFER) == GL_FRAMEBUFFER_COMPLETE;
+
+    g_fbo.bind_framebuffer(GL_FRAMEBUFFER, (GLuint)previous_fbo);
+    glBindTexture(GL_TEXTURE_2D, (GLuint)previous_texture);
+    if (!complete) {
+        g_fbo.delete_framebuffers(1, &fbo);
+        glDeleteTextures(1, &texture);
+        return 0;
+    }
+    *out_texture = texture;
+    return fbo;
+}
+
+void imgui_render_target_resize(unsigned int texture, int width, int height) {
+    GLint previous;
+    glGetIntegerv(GL_TEXTURE_BINDING_2D, &previous);
+    glBindTexture(GL_TEXTURE_2D, texture);
+    glTexImage2D(GL_TEXTURE_2D, 0, GL_RGBA8, width, height, 0, GL_RGBA, GL_UNSIGNED_BYTE, nullptr);
+    glBindTexture(GL_TEXTURE_2D, (GLuint)previous);
+}
+
+void imgui_render_target_destroy(unsigned int fbo, unsigned int texture) {
+    if (load_fbo_functions()) g_fbo.delete_framebuffers(1, &fbo);
+    glDeleteTextures(1, &texture);
+}
+
+void imgui_render_target_bind(unsigned int fbo, int x, int y, int width, int height, int saved[9]) {
+    glGetIntegerv(GL_FRAMEBUFFER_BINDING, &saved[0]);
+    glGetIntegerv(GL_VIEWPORT, &saved[1]);
+    GLboolean mask[4];
+    glGetBooleanv(GL_COLOR_WRITEMASK, mask);
+    for (int i = 0; i < 4; i++) saved[5 + i] = mask[i];
+    g_fbo.bind_framebuffer(GL_FRAMEBUFFER, fbo);
+    glViewport(x, y, width, height);
+}
+
+void imgui_render_target_unbind(const int saved[9]) {
+    g_fbo.bind_framebuffer(GL_FRAMEBUFFER, (GLuint)saved[0]);
+    glViewport(saved[1], saved[2], saved[3], saved[4]);
+    glColorMask((GLboolean)saved[5], (GLboolean)saved[6], (GLboolean)saved[7], (GLboolean)saved[8]);
+}
+
+void imgui_render_target_clear(float r, float g, float b, float a) {
+    GLfloat previous[4];
+    glGetFloatv(GL_COLOR_CLEAR_VALUE, previous);
+    GLboolean scissor = glIsEnabled(GL_SCISSOR_TEST);
+    glDisable(GL_SCISSOR_TEST);
+    glColorMask(GL_TRUE, GL_TRUE, GL_TRUE, GL_TRUE);
+    glClearColor(r, g, b, a);
+    glClear(GL_COLOR_BUFFER_BIT);
+    glClearColor(previous[0], previous[1], previous[2], previous[3]);
+    if (scissor) glEnable(GL_SCISSOR_TEST);
+}
+
+void imgui_render_target_lock_alpha(int lock) {
+    glColorMask(GL_TRUE, GL_TRUE, GL_TRUE, lock ? GL_FALSE : GL_TRUE);
+}
+
+void imgui_render_target_read_pixels(unsigned int fbo, int width, int height, unsigned char* out_pixels) {
+    GLint previous_fbo, previous_alignment;
+    glGetIntegerv(GL_FRAMEBUFFER_BINDING, &previous_fbo);
+    glGetIntegerv(GL_PACK_ALIGNMENT, &previous_alignment);
+    g_fbo.bind_framebuffer(GL_FRAMEBUFFER, fbo);
+    glPixelStorei(GL_PACK_ALIGNMENT, 1);
+    glReadPixels(0, 0, width, height, GL_RGBA, GL_UNSIGNED_BYTE, out_pixels);
+    glPixelStorei(GL_PACK_ALIGNMENT, previous_alignment);
+    g_fbo.bind_framebuffer(GL_FRAMEBUFFER, (GLuint)previous_fbo);
+}
+
+int imgui_draw_data_framebuffer_size(int out_size[2]) {
+    ImDrawData* draw_data = ImGui::GetDrawData();
+    if (!draw_data) return 0;
+    out_size[0] = (int)(draw_data->DisplaySize.x * draw_data->FramebufferScale.x);
+    out_size[1] = (int)(draw_data->DisplaySize.y * draw_data->FramebufferScale.y);
+    return 1;
+}
+
+// The backend sizes its viewport from the framebuffer scale, so the scale is
+// swapped for one that makes the display cover the whole target.
+int imgui_opengl3_render_draw_data_to(unsigned int fbo, int width, int height) {
+    ImDrawData* draw_data = ImGui::GetDrawData();
+    if (!draw_data || draw_data->DisplaySize.x <= 0.0f || draw_data->DisplaySize.y <= 0.0f ||
+        !ImGui::GetIO().BackendRendererUserData || !load_fbo_functions()) {
+        return 0;
+    }
+    ImVec2 scale = draw_data->FramebufferScale;
+    draw_data->FramebufferScale = ImVec2((float)width / draw_data->DisplaySize.x, (float)height / draw_data->DisplaySize.y);
+
+    GLint previous_fbo;
+    glGetIntegerv(GL_FRAMEBUFFER_BINDING, &previous_fbo);
+    g_fbo.bind_framebuffer(GL_FRAMEBUFFER, fbo);
+    ImGui_ImplOpenGL3_RenderDrawData(draw_data);
+    g_fbo.bind_framebuffer(GL_FRAMEBUFFER, (GLuint)previous_fbo);
+
+    draw_data->FramebufferScale = scale;
+    return 1;
+}
+
+// DPI scaling
 float imgui_get_dpi_scale(GLFWwindow* window) {
     float x_scale, y_scale;
     glfwGetWindowContentScale(window, &x_scale, &y_scale);
-    return x_scale; // x and y are typically the same on Windows
+    return x_scale;
 }
 
-void imgui_apply_dpi_scale(GLFWwindow* window) {
-    float scale = imgui_get_dpi_scale(window);
-    if (scale <= 0.0f) scale = 1.0f;
-
-    // Scale all style sizes
-    ImGuiStyle& style = ImGui::GetStyle();
-    style.ScaleAllSizes(scale);
-
-    // Rebuild font atlas with scaled font size
-    ImGuiIO& io = ImGui::GetIO();
-    io.Fonts->Clear();
-
-    // Load default font at scaled size (13.0f is ImGui's default font size)
-    float font_size = 13.0f * scale;
-    io.Fonts->AddFontDefault(nullptr);
-
-    // Scale the default font
-    ImFontConfig config;
-    config.SizePixels = font_size;
-    config.OversampleH = 1;
-    config.OversampleV = 1;
-    config.PixelSnapH = true;
-    io.Fonts->Clear();
-    io.Fonts->AddFontDefault(&config);
-    io.Fonts->Build();
-
-    // Set global font scale to 1.0 since we've already scaled the font
-    io.FontGlobalScale = 1.0f;
+// Framebuffer pixels per window coordinate, e.g. 2.0 on macOS Retina displays.
+// Returns 0 while the window is minimized, since its size is then zero.
+float imgui_get_framebuffer_scale(GLFWwindow* window) {
+    int width, height, fb_width, fb_height;
+    glfwGetWindowSize(window, &width, &height);
+    glfwGetFramebufferSize(window, &fb_width, &fb_height);
+    return width > 0 && fb_width > 0 ? (float)fb_width / (float)width : 0.0f;
 }
 
 } // extern "C"
@@ -1,16 +1,28 @@
 #ifndef IMGUI_WRAPPER_H
 #define IMGUI_WRAPPER_H
 
+#include <stddef.h>
+#include <stdint.h>
+
 #ifdef __cplusplus
 extern "C" {
 #endif
 
 // Forward declaration for GLFW window
 struct GLFWwindow;
+struct ImGuiStyle;
+struct ImGuiInputTextCallbackData;
+
+// Strings are passed as [str, str_end) ranges following ImGui's text_end
+// convention, so callers do not need to NUL-terminate them.
 
 // Context management
 void* imgui_create_context(void);
 void imgui_destroy_context(void* ctx);
+void imgui_set_current_context(void* ctx);
+// A context without platform or renderer backend, which does not save
+// imgui.ini. It starts with the given display size and a 60 Hz time step.
+void* imgui_create_headless_context(float display_width, float display_height);
 
 // Backend initialization/shutdown
 int imgui_init_for_glfw(GLFWwindow* window, int install_callbacks);
@@ -26,23 +38,128 @@ void imgui_end_frame(void);
 // OpenGL3 backend rendering
 void imgui_opengl3_render_draw_data(void);
 
+// Draw data of the last rendered frame. `imgui_get_draw_data` returns 0 if
+// there is none; the other functions must only be called when it returns 1,
+// with indices below the reported counts. Vertices are ImDrawVert and
+// indices 16-bit.
+typedef struct ImGuiWrapperDrawData {
+    float display_pos[2];
+    float display_size[2];
+    float framebuffer_scale[2];
+    int cmd_lists_count;
+    int total_vtx_count;
+    int total_idx_count;
+} ImGuiWrapperDrawData;
+
+typedef struct ImGuiWrapperDrawList {
+    const void* vtx_buffer;
+    int vtx_count;
+    const unsigned short* idx_buffer;
+    int idx_count;
+    int cmd_count;
+} ImGuiWrapperDrawList;
+
+typedef struct ImGuiWrapperDrawCmd {
+    float clip_rect[4];
+    uint64_t texture_id;
+    unsigned int vtx_offset;
+    unsigned int idx_offset;
+    unsigned int elem_count;
+    int has_callback;
+} ImGuiWrapperDrawCmd;
+
+int imgui_get_draw_data(ImGuiWrapperDrawData* out);
+void imgui_get_draw_list(int index, ImGuiWrapperDrawList* out);
+void imgui_get_draw_cmd(int list_index, int cmd_index, ImGuiWrapperDrawCmd* out);
+
 // IO access
 void imgui_io_set_display_size(float width, float height);
+void imgui_io_set_delta_time(float delta_time);
+void imgui_io_add_mouse_pos_event(float x, float y);
+void imgui_io_add_mouse_button_event(int button, int down);
+void imgui_io_add_mouse_wheel_event(float wheel_x, float wheel_y);
+void imgui_io_add_key_event(int key, int down);
+void imgui_io_add_input_character(unsigned int c);
 int imgui_io_want_capture_mouse(void);
 int imgui_io_want_capture_keyboard(void);
+void imgui_io_display_framebuffer_scale(float out_scale[2]);
 
 // Basic widgets
-int imgui_begin(const char* name, int* p_open, int flags);
+int imgui_begin(const char* name, const char* name_end, int* p_open, int flags);
 void imgui_end(void);
-void imgui_text(const char* text);
-int imgui_button(const char* label);
-int imgui_checkbox(const char* label, int* v);
-int imgui_slider_float(const char* label, float* v, float v_min, float v_max);
-int imgui_slider_int(const char* label, int* v, int v_min, int v_max);
-int imgui_input_float(const char* label, float* v);
-int imgui_input_int(const char* label, int* v);
-int imgui_color_edit3(const char* label, float col[3]);
-int imgui_color_edit4(const char* label, float col[4]);
+void imgui_text(const char* text, const char* text_end);
+void imgui_text_colored(float r, float g, float b, float a, const char* text, const char* text_end);
+void imgui_text_disabled(const char* text, const char* text_end);
+void imgui_text_wrapped(const char* text, const char* text_end);
+void imgui_label_text(const char* label, const char* label_end, const char* text, const char* text_end);
+void imgui_separator_text(const char* label, const char* label_end);
+void imgui_push_text_wrap_pos(float wrap_local_pos_x);
+void imgui_pop_text_wrap_pos(void);
+void imgui_calc_text_size(const char* text, const char* text_end, float out_size[2]);
+int imgui_button(const char* label, const char* label_end);
+int imgui_small_button(const char* label, const char* label_end);
+int imgui_arrow_button(const char* str_id, const char* str_id_end, int dir);
+int imgui_invisible_button(const char* str_id, const char* str_id_end, float width, float height, int flags);
+int imgui_radio_button(const char* label, const char* label_end, int active);
+void imgui_progress_bar(float fraction, float width, float height, const char* overlay, const char* overlay_end);
+void imgui_bullet(void);
+void imgui_bullet_text(const char* text, const char* text_end);
+int imgui_text_link(const char* label, const char* label_end);
+void imgui_text_link_open_url(const char* label, const char* label_end, const char* url, const char* url_end);
+int imgui_checkbox(const char* label, const char* label_end, int* v);
+int imgui_slider_float(const char* label, const char* label_end, float* v, float v_min, float v_max);
+int imgui_slider_int(const char* label, const char* label_end, int* v, int v_min, int v_max);
+int imgui_drag_float(const char* label, const char* label_end, float* v, float v_speed, float v_min, float v_max,
+                     const char* format, const char* format_end, int flags);
+int imgui_drag_int(const char* label, const char* label_end, int* v, float v_speed, int v_min, int v_max,
+                   const char* format, const char* format_end, int flags);
+int imgui_drag_float_range2(const char* label, const char* label_end, float* v_current_min, float* v_current_max,
+                            float v_speed, float v_min, float v_max, const char* format, const char* format_end,
+                            const char* format_max, const char* format_max_end, int flags);
+int imgui_drag_int_range2(const char* label, const char* label_end, int* v_current_min, int* v_current_max,
+                          float v_speed, int v_min, int v_max, const char* format, const char* format_end,
+                          const char* format_max, const char* format_max_end, int flags);
+int imgui_slider_scalar_n(const char* label, const char* label_end, int data_type, void* p_data, int components,
+                          const void* p_min, const void* p_max, const char* format, const char* format_end, int flags);
+int imgui_v_slider_scalar(const char* label, const char* label_end, float width, float height, int data_type,
+                          void* p_data, const void* p_min, const void* p_max, const char* format, const char* format_end,
+                          int flags);
+int imgui_slider_angle(const char* label, const char* label_end, float* v_rad, float v_degrees_min, float v_degrees_max,
+                       const char* format, const char* format_end, int flags);
+int imgui_drag_scalar_n(const char* label, const char* label_end, int data_type, void* p_data, int components,
+                        float v_speed, const void* p_min, const void* p_max, const char* format, const char* format_end,
+                        int flags);
+int imgui_input_scalar_n(const char* label, const char* label_end, int data_type, void* p_data, int components,
+                         const void* p_step, const void* p_step_fast, const char* format, const char* format_end,
+                         int flags);
+int imgui_input_float(const char* label, const char* label_end, float* v);
+int imgui_input_int(const char* label, const char* label_end, int* v);
+
+// Text input. `buf` must hold a NUL-terminated string within `buf_size`
+// bytes; the callback receives `user_data` and handles resize requests.
+typedef int (*ImGuiWrapperInputTextCallback)(ImGuiInputTextCallbackData* data);
+int imgui_input_text(const char* label, const char* label_end, char* buf, size_t buf_size, int flags,
+                     ImGuiWrapperInputTextCallback callback, void* user_data);
+int imgui_input_text_multiline(const char* label, const char* label_end, char* buf, size_t buf_size,
+                               float width, float height, int flags,
+                               ImGuiWrapperInputTextCallback callback, void* user_data);
+int imgui_input_text_with_hint(const char* label, const char* label_end, const char* hint, const char* hint_end,
+                               char* buf, size_t buf_size, int flags,
+                               ImGuiWrapperInputTextCallback callback, void* user_data);
+void imgui_input_text_callback_insert_chars(ImGuiInputTextCallbackData* data, int pos, const char* text, const char* text_end);
+void imgui_input_text_callback_delete_chars(ImGuiInputTextCallbackData* data, int pos, int bytes_count);
+// Images
+void imgui_image(uint64_t texture_id, const float size[2], const float uv0[2], const float uv1[2],
+                 const float tint_col[4], const float border_col[4]);
+int imgui_image_button(const char* str_id, const char* str_id_end, uint64_t texture_id, const float size[2],
+                       const float uv0[2], const float uv1[2], const float bg_col[4], const float tint_col[4]);
+
+int imgui_color_edit3(const char* label, const char* label_end, float col[3], int flags);
+int imgui_color_edit4(const char* label, const char* label_end, float col[4], int flags);
+int imgui_color_picker3(const char* label, const char* label_end, float col[3], int flags);
+int imgui_color_picker4(const char* label, const char* label_end, float col[4], int flags, const float* ref_col);
+int imgui_color_button(const char* desc_id, const char* desc_id_end, const float col[4], int flags, float width, float height);
+void imgui_set_color_edit_options(int flags);
 void imgui_same_line(void);
 void imgui_separator(void);
 void imgui_spacing(void);
@@ -51,44 +168,44 @@ void imgui_indent(float indent_w);
 void imgui_unindent(float indent_w);
 
 // Tree nodes
-int imgui_tree_node(const char* label);
+int imgui_tree_node(const char* label, const char* label_end);
 void imgui_tree_pop(void);
 
 // Combo box
-int imgui_begin_combo(const char* label, const char* preview_value, int flags);
+int imgui_begin_combo(const char* label, const char* label_end, const char* preview_value, const char* preview_value_end, int flags);
 void imgui_end_combo(void);
-int imgui_selectable(const char* label, int selected, int flags);
+int imgui_selectable(const char* label, const char* label_end, int selected, int flags);
 
 // Menu
 int imgui_begin_main_menu_bar(void);
 void imgui_end_main_menu_bar(void);
-int imgui_begin_menu(const char* label, int enabled);
+int imgui_begin_menu(const char* label, const char* label_end, int enabled);
 void imgui_end_menu(void);
-int imgui_menu_item(const char* label, const char* shortcut, int selected, int enabled);
+int imgui_menu_item(const char* label, const char* label_end, const char* shortcut, const char* shortcut_end, int selected, int enabled);
 
 // Tooltips
-void imgui_set_tooltip(const char* text);
+void imgui_set_tooltip(const char* text, const char* text_end);
 int imgui_begin_tooltip(void);
 void imgui_end_tooltip(void);
 
 // Popups
-int imgui_begin_popup(const char* str_id, int flags);
-int imgui_begin_popup_modal(const char* name, int* p_open, int flags);
+int imgui_begin_popup(const char* str_id, const char* str_id_end, int flags);
+int imgui_begin_popup_modal(const char* name, const char* name_end, int* p_open, int flags);
 void imgui_end_popup(void);
-void imgui_open_popup(const char* str_id);
+void imgui_open_popup(const char* str_id, const char* str_id_end);
 void imgui_close_current_popup(void);
 
 // Tables
-int imgui_begin_table(const char* str_id, int column, int flags);
+int imgui_begin_table(const char* str_id, const char* str_id_end, int column, int flags);
 void imgui_end_table(void);
 void imgui_table_next_row(void);
 int imgui_table_next_column(void);
 int imgui_table_set_column_index(int column_n);
-void imgui_table_setup_column(const char* label, int flags, float init_width_or_weight);
+void imgui_table_setup_column(const char* label, const char* label_end, int flags, float init_width_or_weight);
 void imgui_table_headers_row(void);
 
 // Columns (legacy)
-void imgui_columns(int count, const char* id, int border);
+void imgui_columns(int count, const char* id, const char* id_end, int border);
 void imgui_next_column(void);
 
 // Style
@@ -98,24 +215,85 @@ void imgui_push_style_var_float(int idx, float val);
 void imgui_push_style_var_vec2(int idx, float x, float y);
 void imgui_pop_style_var(int count);
 
+// Persistent style
+void imgui_get_style(ImGuiStyle* out);
+void imgui_set_style(const ImGuiStyle* style);
+void imgui_style_default(ImGuiStyle* out);
+void imgui_style_colors_dark(ImGuiStyle* dst);
+void imgui_style_colors_light(ImGuiStyle* dst);
+void imgui_style_colors_classic(ImGuiStyle* dst);
+void imgui_style_scale_all_sizes(ImGuiStyle* style, float factor);
+
 // ID stack
 void imgui_push_id_int(int int_id);
-void imgui_push_id_str(const char* str_id);
+void imgui_push_id_str(const char* str_id, const char* str_id_end);
 void imgui_pop_id(void);
 
 // Utilities
 int imgui_is_item_hovered(void);
 int imgui_is_item_clicked(int mouse_button);
 int imgui_is_item_active(void);
+void imgui_get_content_region_avail(float out_size[2]);
+void imgui_get_cursor_screen_pos(float out_pos[2]);
+void imgui_get_mouse_pos(float out_pos[2]);
 void imgui_set_next_window_pos(float x, float y, int cond);
 void imgui_set_next_window_size(float width, float height, int cond);
 
+// Fonts. Font data and glyph ranges are borrowed, the caller keeps them
+// alive while the atlas uses them. Functions returning a font return its
+// index in the atlas, or -1.
+
+// Mirrors `RawFontConfig` in src/font.rs. Glyph ranges are ImWchar pairs
+// terminated by 0, or NULL for the default ranges.
+typedef struct ImGuiWrapperFontConfig {
+    int merge_mode;
+    int pixel_snap_h;
+    int oversample_h;
+    int oversample_v;
+    float glyph_offset_x;
+    float glyph_offset_y;
+    float glyph_extra_spacing_x;
+    float glyph_min_advance_x;
+    float glyph_max_advance_x;
+    float rasterizer_multiply;
+    const unsigned short* glyph_ranges;
+} ImGuiWrapperFontConfig;
+
+void imgui_fonts_clear(void);
+int imgui_fonts_add_default(float size_pixels, const ImGuiWrapperFontConfig* config);
+int imgui_fonts_add_ttf(const void* data, int data_size, float size_pixels, const ImGuiWrapperFontConfig* config);
+const unsigned short* imgui_glyph_ranges(int set);
+int imgui_fonts_build(void);
+// Builds the atlas if needed; returns its texture ID. The pixels stay valid
+// until the atlas is changed.
+uint64_t imgui_fonts_tex_data_rgba32(const unsigned char** out_pixels, int* out_width, int* out_height);
+void imgui_push_font(int index);
+void imgui_pop_font(void);
+
+// Offscreen render targets: a framebuffer object with an RGBA8 color
+// texture. `create` returns the framebuffer name, or 0 on failure. `bind`
+// saves the previous framebuffer, viewport and color mask into `saved` for
+// `unbind`. `lock_alpha` stops draws from changing the alpha channel.
+unsigned int imgui_render_target_create(int width, int height, unsigned int* out_texture);
+void imgui_render_target_resize(unsigned int texture, int width, int height);
+void imgui_render_target_destroy(unsigned int fbo, unsigned int texture);
+void imgui_render_target_bind(unsigned int fbo, int x, int y, int width, int height, int saved[9]);
+void imgui_render_target_unbind(const int saved[9]);
+void imgui_render_target_clear(float r, float g, float b, float a);
+void imgui_render_target_lock_alpha(int lock);
+// Reads bottom-up RGBA rows into `out_pixels`, which holds width * height * 4 bytes.
+void imgui_render_target_read_pixels(unsigned int fbo, int width, int height, unsigned char* out_pixels);
+
+// Draw data of the last rendered frame. Both return 0 if there is none.
+int imgui_draw_data_framebuffer_size(int out_size[2]);
+int imgui_opengl3_render_draw_data_to(unsigned int fbo, int width, int height);
+
 // Demo window (useful for testing)
 void imgui_show_demo_window(int* p_open);
 
-// DPI scaling (for Windows high-DPI displays)
+// DPI scaling
 float imgui_get_dpi_scale(GLFWwindow* window);
-void imgui_apply_dpi_scale(GLFWwindow* window);
+float imgui_get_framebuffer_scale(GLFWwindow* window);
 
 #ifdef __cplusplus
 }
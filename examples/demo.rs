@@ -29,7 +29,8 @@ fn main() {
     ));
 
     // Initialize ImGui
-    let imgui = ImGui::new(app.window.glfw_window_ptr(), true);
+    let mut imgui =
+        ImGui::new(app.window.glfw_window_ptr(), true).expect("failed to initialize ImGui");
 
     // Shared state (modified by ImGui, read by pre_render)
     let pos_x = Rc::new(RefCell::new(400.0f32));
@@ -51,18 +52,18 @@ fn main() {
 
     // ImGui controls
     app.on_render(move |_renderer| {
-        imgui.new_frame();
+        let ui = imgui.new_frame();
 
-        imgui.begin("Shape Controls", None, 0);
-        imgui.text("Position");
-        imgui.slider_float("X", &mut pos_x.borrow_mut(), 0.0, 800.0);
-        imgui.slider_float("Y", &mut pos_y.borrow_mut(), 0.0, 600.0);
-        imgui.separator();
-        imgui.text("Transform");
-        imgui.slider_float("Scale", &mut scale.borrow_mut(), 0.1, 3.0);
-        imgui.end();
+        ui.window("Shape Controls").build(|| {
+            ui.text("Position");
+            ui.slider_float("X", &mut pos_x.borrow_mut(), 0.0, 800.0);
+            ui.slider_float("Y", &mut pos_y.borrow_mut(), 0.0, 600.0);
+            ui.separator();
+            ui.text("Transform");
+            ui.slider_float("Scale", &mut scale.borrow_mut(), 0.1, 3.0);
+        });
 
-        imgui.render();
+        ui.render();
     });
 
     app.run();
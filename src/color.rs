@@ -0,0 +1,111 @@
+//! Conversions between `wilhelm_renderer::core::Color` and the float arrays
+//! used by the color widgets.
+//!
+//! `Color` can only be built from RGB, so its alpha is always 1.0 and the
+//! RGB conversions round-trip exactly. `rgb_to_hsv()` and `hsv_to_rgb()`
+//! convert for widgets used with `ColorEditFlags::INPUT_HSV`.
+
+use wilhelm_renderer::core::Color;
+
+/// The RGB components of `color`.
+pub fn color_to_rgb(color: Color) -> [f32; 3] {
+    [color.red_value(), color.green_value(), color.blue_value()]
+}
+
+/// The RGBA components of `color`.
+pub fn color_to_rgba(color: Color) -> [f32; 4] {
+    [color.red_value(), color.green_value(), color.blue_value(), color.alpha()]
+}
+
+/// A color from RGB components.
+pub fn color_from_rgb(rgb: [f32; 3]) -> Color {
+    Color::from_rgb(rgb[0], rgb[1], rgb[2])
+}
+
+/// Convert RGB to HSV, all components in 0..1. Same as ImGui's
+/// `ColorConvertRGBtoHSV`.
+pub fn rgb_to_hsv(rgb: [f32; 3]) -> [f32; 3] {
+    let [mut r, mut g, mut b] = rgb;
+    let mut k = 0.0;
+    if g < b {
+        std::mem::swap(&mut g, &mut b);
+        k = -1.0;
+    }
+    if r < g {
+        std::mem::swap(&mut r, &mut g);
+        k = -2.0 / 6.0 - k;
+    }
+    let chroma = r - g.min(b);
+    let h = (k + (g - b) / (6.0 * chroma + 1e-20)).abs();
+    let s = chroma / (r + 1e-20);
+    [h, s, r]
+}
+
+/// Convert HSV to RGB, all components in 0..1. Same as ImGui's
+/// `ColorConvertHSVtoRGB`.
+pub fn hsv_to_rgb(hsv: [f32; 3]) -> [f32; 3] {
+    let [h, s, v] = hsv;
+    if s == 0.0 {
+        return [v, v, v];
+    }
+    let h = (h % 1.0) / (60.0 / 360.0);
+    let i = h as i32;
+    let f = h - i as f32;
+    let p = v * (1.0 - s);
+    let q = v * (1.0 - s * f);
+    let t = v * (1.0 - s * (1.0 - f));
+    match i {
+        0 => [v, t, p],
+        1 => [q, v, p],
+        2 => [p, v, t],
+        3 => [p, q, v],
+        4 => [t, p, v],
+        _ => [v, p, q],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(actual: [f32; 3], expected: [f32; 3]) {
+        assert!(
+            actual.iter().zip(expected).all(|(a, e)| (a - e).abs() < 1e-5),
+            "{:?} != {:?}",
+            actual,
+            expected
+        );
+    }
+
+    #[test]
+    fn primaries_convert_both_ways() {
+        let cases = [
+            ([1.0, 0.0, 0.0], [0.0, 1.0, 1.0]),
+            ([0.0, 1.0, 0.0], [1.0 / 3.0, 1.0, 1.0]),
+            ([0.0, 0.0, 1.0], [2.0 / 3.0, 1.0, 1.0]),
+            ([1.0, 1.0, 0.0], [1.0 / 6.0, 1.0, 1.0]),
+            ([0.0, 0.5, 0.5], [0.5, 1.0, 0.5]),
+        ];
+        for (rgb, hsv) in cases {
+            assert_close(rgb_to_hsv(rgb), hsv);
+            assert_close(hsv_to_rgb(hsv), rgb);
+        }
+    }
+
+    #[test]
+    fn grays_have_no_saturation() {
+        for v in [0.0, 0.25, 1.0] {
+            let hsv = rgb_to_hsv([v, v, v]);
+            assert_eq!(hsv[1], 0.0);
+            assert_eq!(hsv[2], v);
+            assert_eq!(hsv_to_rgb([0.7, 0.0, v]), [v, v, v]);
+        }
+    }
+
+    #[test]
+    fn hue_wraps_at_one() {
+        assert_close(hsv_to_rgb([1.0, 1.0, 1.0]), [1.0, 0.0, 0.0]);
+        assert_close(hsv_to_rgb([1.25, 1.0, 1.0]), hsv_to_rgb([0.25, 1.0, 1.0]));
+        assert_close(hsv_to_rgb([2.0, 1.0, 1.0]), [1.0, 0.0, 0.0]);
+    }
+}
@@ -0,0 +1,137 @@
+//! Numeric types accepted by the `*_scalar` widgets.
+
+use std::ops::RangeInclusive;
+
+use crate::ImGuiError;
+
+/// A Rust numeric type that maps to an `ImGuiDataType`.
+///
+/// Implemented for every integer and float primitive; `isize` and `usize`
+/// use the 32- or 64-bit type matching the target's pointer width. The
+/// trait is sealed.
+pub trait DataType: Copy + sealed::Sealed {}
+
+mod sealed {
+    pub trait Sealed: Copy + PartialOrd + std::fmt::Debug {
+        /// The `ImGuiDataType` value.
+        const ID: i32;
+        /// The smallest slider minimum ImGui accepts.
+        const SLIDER_MIN: Self;
+        /// The largest slider maximum ImGui accepts.
+        const SLIDER_MAX: Self;
+    }
+}
+
+pub(crate) const S8: i32 = 0;
+pub(crate) const U8: i32 = 1;
+pub(crate) const S16: i32 = 2;
+pub(crate) const U16: i32 = 3;
+pub(crate) const S32: i32 = 4;
+pub(crate) const U32: i32 = 5;
+pub(crate) const S64: i32 = 6;
+pub(crate) const U64: i32 = 7;
+pub(crate) const FLOAT: i32 = 8;
+pub(crate) const DOUBLE: i32 = 9;
+
+macro_rules! data_types {
+    ($($ty:ty => $id:expr, $min:expr, $max:expr);* $(;)?) => {
+        $(
+            impl sealed::Sealed for $ty {
+                const ID: i32 = $id;
+                const SLIDER_MIN: Self = $min;
+                const SLIDER_MAX: Self = $max;
+            }
+            impl DataType for $ty {}
+        )*
+    };
+}
+
+// Sliders on 32-bit and wider types need half of the type's range
+data_types!(
+    i8 => S8, i8::MIN, i8::MAX;
+    u8 => U8, u8::MIN, u8::MAX;
+    i16 => S16, i16::MIN, i16::MAX;
+    u16 => U16, u16::MIN, u16::MAX;
+    i32 => S32, i32::MIN / 2, i32::MAX / 2;
+    u32 => U32, u32::MIN, u32::MAX / 2;
+    i64 => S64, i64::MIN / 2, i64::MAX / 2;
+    u64 => U64, u64::MIN, u64::MAX / 2;
+    f32 => FLOAT, -f32::MAX / 2.0, f32::MAX / 2.0;
+    f64 => DOUBLE, -f64::MAX / 2.0, f64::MAX / 2.0;
+);
+
+#[cfg(target_pointer_width = "64")]
+data_types!(isize => S64, isize::MIN / 2, isize::MAX / 2; usize => U64, usize::MIN, usize::MAX / 2);
+#[cfg(target_pointer_width = "32")]
+data_types!(isize => S32, isize::MIN / 2, isize::MAX / 2; usize => U32, usize::MIN, usize::MAX / 2);
+
+/// The `ImGuiDataType` value of `T`.
+pub(crate) fn data_type_id<T: DataType>() -> i32 {
+    <T as sealed::Sealed>::ID
+}
+
+/// Fail if ImGui's `SliderBehavior` would assert on `range`: the minimum
+/// must not be below, and the maximum not above, half of the type's limits
+/// for 32-bit and wider types. NaN bounds are rejected.
+pub(crate) fn check_slider_range<T: DataType>(range: &RangeInclusive<T>) -> Result<(), ImGuiError> {
+    if slider_range(range.clone()) == *range {
+        return Ok(());
+    }
+    Err(ImGuiError::InvalidRange {
+        message: format!(
+            "{:?}..={:?} is outside {:?}..={:?}",
+            range.start(),
+            range.end(),
+            T::SLIDER_MIN,
+            T::SLIDER_MAX
+        ),
+    })
+}
+
+/// `range` with its bounds clamped to what ImGui's sliders accept. NaN
+/// bounds become the limit on that side.
+pub(crate) fn slider_range<T: DataType>(range: RangeInclusive<T>) -> RangeInclusive<T> {
+    let (start, end) = range.into_inner();
+    // Written so that a NaN bound fails the comparison and is replaced
+    let start = if T::SLIDER_MIN <= start { start } else { T::SLIDER_MIN };
+    let end = if end <= T::SLIDER_MAX { end } else { T::SLIDER_MAX };
+    start..=end
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn small_integer_ranges_are_never_clamped() {
+        assert_eq!(slider_range(i8::MIN..=i8::MAX), i8::MIN..=i8::MAX);
+        assert_eq!(slider_range(0..=u16::MAX), 0..=u16::MAX);
+        assert_eq!(check_slider_range(&(i16::MIN..=i16::MAX)), Ok(()));
+    }
+
+    #[test]
+    fn wide_ranges_are_clamped_to_half_the_limits() {
+        assert_eq!(slider_range(i32::MIN..=i32::MAX), i32::MIN / 2..=i32::MAX / 2);
+        assert_eq!(slider_range(0..=u64::MAX), 0..=u64::MAX / 2);
+        assert_eq!(slider_range(-f32::MAX..=1.0), -f32::MAX / 2.0..=1.0);
+        assert_eq!(slider_range(0.0..=f64::INFINITY), 0.0..=f64::MAX / 2.0);
+        assert_eq!(slider_range(-5..=5), -5..=5);
+    }
+
+    #[test]
+    fn nan_bounds_are_replaced() {
+        assert_eq!(slider_range(f32::NAN..=f32::NAN), -f32::MAX / 2.0..=f32::MAX / 2.0);
+        assert!(check_slider_range(&(0.0..=f64::NAN)).is_err());
+    }
+
+    #[test]
+    fn out_of_range_bounds_are_rejected() {
+        for range in [i32::MIN..=0, 0..=i32::MAX] {
+            assert!(matches!(check_slider_range(&range), Err(ImGuiError::InvalidRange { .. })));
+        }
+        assert!(check_slider_range(&(0..=u32::MAX)).is_err());
+        assert!(check_slider_range(&(i64::MIN..=i64::MAX)).is_err());
+        assert_eq!(check_slider_range(&(0..=u32::MAX / 2)), Ok(()));
+        assert_eq!(check_slider_range(&(-1.0..=1.0)), Ok(()));
+    }
+}
@@ -0,0 +1,208 @@
+//! Read-only views of the geometry ImGui produced for a frame.
+
+use std::os::raw::{c_int, c_uint, c_void};
+use std::slice;
+
+use crate::{ffi, TextureId};
+
+/// Mirrors `ImGuiWrapperDrawData` in cpp/imgui_wrapper.h.
+#[repr(C)]
+#[derive(Default)]
+pub(crate) struct RawDrawData {
+    display_pos: [f32; 2],
+    display_size: [f32; 2],
+    framebuffer_scale: [f32; 2],
+    cmd_lists_count: c_int,
+    total_vtx_count: c_int,
+    total_idx_count: c_int,
+}
+
+/// Mirrors `ImGuiWrapperDrawList`.
+#[repr(C)]
+pub(crate) struct RawDrawList {
+    vtx_buffer: *const c_void,
+    vtx_count: c_int,
+    idx_buffer: *const u16,
+    idx_count: c_int,
+    cmd_count: c_int,
+}
+
+/// Mirrors `ImGuiWrapperDrawCmd`.
+#[repr(C)]
+#[derive(Default)]
+pub(crate) struct RawDrawCmd {
+    clip_rect: [f32; 4],
+    texture_id: u64,
+    vtx_offset: c_uint,
+    idx_offset: c_uint,
+    elem_count: c_uint,
+    has_callback: c_int,
+}
+
+/// A vertex as laid out by ImGui (`ImDrawVert`).
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DrawVert {
+    /// Position in display coordinates.
+    pub pos: [f32; 2],
+    /// Texture coordinates.
+    pub uv: [f32; 2],
+    /// Color packed as RGBA bytes in memory order.
+    pub col: u32,
+}
+
+impl DrawVert {
+    /// The vertex color as `[r, g, b, a]` bytes.
+    pub fn color(&self) -> [u8; 4] {
+        self.col.to_le_bytes()
+    }
+}
+
+/// A run of triangles sharing a texture and clip rectangle.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DrawCmd {
+    /// Clip rectangle `[x1, y1, x2, y2]` in display coordinates.
+    pub clip_rect: [f32; 4],
+    /// Texture to sample; the font atlas for text and most widgets.
+    pub texture: TextureId,
+    /// Offset added to every index of this command.
+    pub vtx_offset: usize,
+    /// First index of this command in `DrawList::indices()`.
+    pub idx_offset: usize,
+    /// Number of indices, three per triangle.
+    pub elem_count: usize,
+}
+
+/// Geometry of one window or layer, in drawing order.
+#[derive(Debug)]
+pub struct DrawList<'a> {
+    vertices: &'a [DrawVert],
+    indices: &'a [u16],
+    commands: Vec<DrawCmd>,
+}
+
+impl<'a> DrawList<'a> {
+    /// The vertex buffer.
+    pub fn vertices(&self) -> &'a [DrawVert] {
+        self.vertices
+    }
+
+    /// The index buffer.
+    pub fn indices(&self) -> &'a [u16] {
+        self.indices
+    }
+
+    /// Draw commands. Callback commands are left out.
+    pub fn commands(&self) -> &[DrawCmd] {
+        &self.commands
+    }
+
+    /// The three vertices of each triangle drawn by `cmd`.
+    ///
+    /// # Panics
+    /// Panics if `cmd` does not belong to this list.
+    pub fn triangles(&self, cmd: &DrawCmd) -> impl Iterator<Item = [DrawVert; 3]> + 'a {
+        let vertices = &self.vertices[cmd.vtx_offset..];
+        self.indices[cmd.idx_offset..cmd.idx_offset + cmd.elem_count]
+            .chunks_exact(3)
+            .map(move |t| [vertices[t[0] as usize], vertices[t[1] as usize], vertices[t[2] as usize]])
+    }
+}
+
+/// Everything ImGui drew in the last frame, returned by `ImGui::draw_data()`.
+#[derive(Debug)]
+pub struct DrawData<'a> {
+    /// Top-left of the display in display coordinates, usually `[0, 0]`.
+    pub display_pos: [f32; 2],
+    /// Size of the display in display coordinates.
+    pub display_size: [f32; 2],
+    /// Framebuffer pixels per display coordinate.
+    pub framebuffer_scale: [f32; 2],
+    lists: Vec<DrawList<'a>>,
+    total_vtx_count: usize,
+    total_idx_count: usize,
+}
+
+impl DrawData<'_> {
+    /// Draw lists in back-to-front order.
+    pub fn draw_lists(&self) -> &[DrawList<'_>] {
+        &self.lists
+    }
+
+    /// Number of vertices in all lists.
+    pub fn total_vtx_count(&self) -> usize {
+        self.total_vtx_count
+    }
+
+    /// Number of indices in all lists.
+    pub fn total_idx_count(&self) -> usize {
+        self.total_idx_count
+    }
+
+    /// Size of the display in framebuffer pixels.
+    pub fn framebuffer_size(&self) -> [u32; 2] {
+        [
+            (self.display_size[0] * self.framebuffer_scale[0]).max(0.0) as u32,
+            (self.display_size[1] * self.framebuffer_scale[1]).max(0.0) as u32,
+        ]
+    }
+}
+
+/// Read the draw data of the last rendered frame.
+///
+/// # Safety
+/// The returned slices point into ImGui's buffers, which stay valid until
+/// the next `NewFrame()`; the caller ties `'a` to a borrow that prevents it.
+pub(crate) unsafe fn draw_data<'a>() -> Option<DrawData<'a>> {
+    let mut raw = RawDrawData::default();
+    if ffi::imgui_get_draw_data(&mut raw) == 0 {
+        return None;
+    }
+    let lists = (0..raw.cmd_lists_count)
+        .map(|n| {
+            let mut list = RawDrawList {
+                vtx_buffer: std::ptr::null(),
+                vtx_count: 0,
+                idx_buffer: std::ptr::null(),
+                idx_count: 0,
+                cmd_count: 0,
+            };
+            ffi::imgui_get_draw_list(n, &mut list);
+            let commands = (0..list.cmd_count)
+                .filter_map(|i| {
+                    let mut cmd = RawDrawCmd::default();
+                    ffi::imgui_get_draw_cmd(n, i, &mut cmd);
+                    (cmd.has_callback == 0).then(|| DrawCmd {
+                        clip_rect: cmd.clip_rect,
+                        texture: TextureId::new(cmd.texture_id),
+                        vtx_offset: cmd.vtx_offset as usize,
+                        idx_offset: cmd.idx_offset as usize,
+                        elem_count: cmd.elem_count as usize,
+                    })
+                })
+                .collect();
+            DrawList {
+                vertices: raw_slice(list.vtx_buffer as *const DrawVert, list.vtx_count),
+                indices: raw_slice(list.idx_buffer, list.idx_count),
+                commands,
+            }
+        })
+        .collect();
+    Some(DrawData {
+        display_pos: raw.display_pos,
+        display_size: raw.display_size,
+        framebuffer_scale: raw.framebuffer_scale,
+        lists,
+        total_vtx_count: raw.total_vtx_count.max(0) as usize,
+        total_idx_count: raw.total_idx_count.max(0) as usize,
+    })
+}
+
+/// A slice over an ImVector's data, which is null while it is empty.
+unsafe fn raw_slice<'a, T>(data: *const T, len: c_int) -> &'a [T] {
+    if data.is_null() || len <= 0 {
+        &[]
+    } else {
+        slice::from_raw_parts(data, len as usize)
+    }
+}
@@ -0,0 +1,68 @@
+//! Error type for fallible ImGui operations.
+
+use std::fmt;
+use std::path::PathBuf;
+
+/// Errors reported by the ImGui wrapper.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ImGuiError {
+    /// The GLFW window pointer passed to `ImGui::new()` was null.
+    NullWindow,
+    /// `ImGui_ImplGlfw_InitForOpenGL` failed.
+    GlfwInit,
+    /// `ImGui_ImplOpenGL3_Init` failed, usually because no OpenGL 3.3 context is current.
+    OpenGl3Init,
+    /// A string contained a NUL byte at the given byte offset.
+    InteriorNul { position: usize },
+    /// A font could not be loaded or the font atlas could not be built.
+    Font { message: String },
+    /// A theme file could not be read, parsed or written.
+    Theme { path: PathBuf, message: String },
+    /// A printf format does not match the type of the value it formats.
+    InvalidFormat { format: String, message: String },
+    /// An offscreen render target could not be created.
+    RenderTarget { width: u32, height: u32 },
+    /// No frame has been rendered yet, so there is no draw data to draw again.
+    NoDrawData,
+    /// The operation needs the OpenGL3 backend, which headless contexts lack.
+    Headless,
+    /// A raw `i32` is not one of the `ImGuiCond` values.
+    InvalidCond { value: i32 },
+    /// Flags that ImGui does not support for the widget they were given to.
+    InvalidFlags { message: String },
+    /// A slider range beyond the limits ImGui supports for its type.
+    InvalidRange { message: String },
+    /// A style value that ImGui would reject when the next frame starts.
+    InvalidStyle { message: String },
+}
+
+impl fmt::Display for ImGuiError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ImGuiError::NullWindow => write!(f, "GLFW window pointer is null"),
+            ImGuiError::GlfwInit => write!(f, "failed to initialize the ImGui GLFW backend"),
+            ImGuiError::OpenGl3Init => write!(f, "failed to initialize the ImGui OpenGL3 backend"),
+            ImGuiError::InteriorNul { position } => {
+                write!(f, "string contains a NUL byte at offset {}", position)
+            }
+            ImGuiError::Font { message } => write!(f, "font error: {}", message),
+            ImGuiError::Theme { path, message } => {
+                write!(f, "theme file {}: {}", path.display(), message)
+            }
+            ImGuiError::InvalidFormat { format, message } => {
+                write!(f, "invalid format {:?}: {}", format, message)
+            }
+            ImGuiError::RenderTarget { width, height } => {
+                write!(f, "failed to create a {}x{} render target", width, height)
+            }
+            ImGuiError::NoDrawData => write!(f, "no ImGui frame has been rendered yet"),
+            ImGuiError::Headless => write!(f, "not available on a headless ImGui context"),
+            ImGuiError::InvalidCond { value } => write!(f, "invalid ImGuiCond value {}", value),
+            ImGuiError::InvalidFlags { message } => write!(f, "invalid flags: {}", message),
+            ImGuiError::InvalidRange { message } => write!(f, "invalid slider range: {}", message),
+            ImGuiError::InvalidStyle { message } => write!(f, "invalid style: {}", message),
+        }
+    }
+}
+
+impl std::error::Error for ImGuiError {}
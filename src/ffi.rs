@@ -0,0 +1,468 @@
+//! FFI declarations for the C wrapper in `cpp/imgui_wrapper.cpp`.
+
+use std::os::raw::{c_char, c_float, c_int, c_uint, c_void};
+use wilhelm_renderer::core::GLFWwindow;
+
+use crate::draw_data::{RawDrawCmd, RawDrawData, RawDrawList};
+use crate::font::RawFontConfig;
+use crate::input_text::{InputTextCallback, RawInputTextCallbackData};
+use crate::Style;
+
+unsafe extern "C" {
+    // Context management
+    pub fn imgui_create_context() -> *mut c_void;
+    pub fn imgui_destroy_context(ctx: *mut c_void);
+    pub fn imgui_set_current_context(ctx: *mut c_void);
+    pub fn imgui_create_headless_context(display_width: c_float, display_height: c_float) -> *mut c_void;
+
+    // Backend initialization/shutdown
+    pub fn imgui_init_for_glfw(window: *const GLFWwindow, install_callbacks: c_int) -> c_int;
+    pub fn imgui_init_for_opengl3(glsl_version: *const c_char) -> c_int;
+    pub fn imgui_shutdown_opengl3();
+    pub fn imgui_shutdown_glfw();
+
+    // Frame management
+    pub fn imgui_new_frame();
+    pub fn imgui_render();
+    pub fn imgui_end_frame();
+
+    // OpenGL3 backend rendering
+    pub fn imgui_opengl3_render_draw_data();
+
+    // Draw data
+    pub fn imgui_get_draw_data(out: *mut RawDrawData) -> c_int;
+    pub fn imgui_get_draw_list(index: c_int, out: *mut RawDrawList);
+    pub fn imgui_get_draw_cmd(list_index: c_int, cmd_index: c_int, out: *mut RawDrawCmd);
+
+    // IO access
+    pub fn imgui_io_set_display_size(width: c_float, height: c_float);
+    pub fn imgui_io_set_delta_time(delta_time: c_float);
+    pub fn imgui_io_add_mouse_pos_event(x: c_float, y: c_float);
+    pub fn imgui_io_add_mouse_button_event(button: c_int, down: c_int);
+    pub fn imgui_io_add_mouse_wheel_event(wheel_x: c_float, wheel_y: c_float);
+    pub fn imgui_io_add_key_event(key: c_int, down: c_int);
+    pub fn imgui_io_add_input_character(c: c_uint);
+    pub fn imgui_io_want_capture_mouse() -> c_int;
+    pub fn imgui_io_want_capture_keyboard() -> c_int;
+    pub fn imgui_io_display_framebuffer_scale(out_scale: *mut c_float);
+
+    // Basic widgets
+    pub fn imgui_begin(
+        name: *const c_char,
+        name_end: *const c_char,
+        p_open: *mut c_int,
+        flags: c_int,
+    ) -> c_int;
+    pub fn imgui_end();
+    pub fn imgui_text(text: *const c_char, text_end: *const c_char);
+    pub fn imgui_text_colored(r: c_float, g: c_float, b: c_float, a: c_float, text: *const c_char, text_end: *const c_char);
+    pub fn imgui_text_disabled(text: *const c_char, text_end: *const c_char);
+    pub fn imgui_text_wrapped(text: *const c_char, text_end: *const c_char);
+    pub fn imgui_label_text(label: *const c_char, label_end: *const c_char, text: *const c_char, text_end: *const c_char);
+    pub fn imgui_separator_text(label: *const c_char, label_end: *const c_char);
+    pub fn imgui_push_text_wrap_pos(wrap_local_pos_x: c_float);
+    pub fn imgui_pop_text_wrap_pos();
+    pub fn imgui_calc_text_size(text: *const c_char, text_end: *const c_char, out_size: *mut c_float);
+    pub fn imgui_button(label: *const c_char, label_end: *const c_char) -> c_int;
+    pub fn imgui_small_button(label: *const c_char, label_end: *const c_char) -> c_int;
+    pub fn imgui_arrow_button(str_id: *const c_char, str_id_end: *const c_char, dir: c_int) -> c_int;
+    pub fn imgui_invisible_button(
+        str_id: *const c_char,
+        str_id_end: *const c_char,
+        width: c_float,
+        height: c_float,
+        flags: c_int,
+    ) -> c_int;
+    pub fn imgui_radio_button(label: *const c_char, label_end: *const c_char, active: c_int) -> c_int;
+    pub fn imgui_progress_bar(
+        fraction: c_float,
+        width: c_float,
+        height: c_float,
+        overlay: *const c_char,
+        overlay_end: *const c_char,
+    );
+    pub fn imgui_bullet();
+    pub fn imgui_bullet_text(text: *const c_char, text_end: *const c_char);
+    pub fn imgui_text_link(label: *const c_char, label_end: *const c_char) -> c_int;
+    pub fn imgui_text_link_open_url(
+        label: *const c_char,
+        label_end: *const c_char,
+        url: *const c_char,
+        url_end: *const c_char,
+    );
+    pub fn imgui_checkbox(label: *const c_char, label_end: *const c_char, v: *mut c_int) -> c_int;
+    pub fn imgui_slider_float(
+        label: *const c_char,
+        label_end: *const c_char,
+        v: *mut c_float,
+        v_min: c_float,
+        v_max: c_float,
+    ) -> c_int;
+    pub fn imgui_slider_int(
+        label: *const c_char,
+        label_end: *const c_char,
+        v: *mut c_int,
+        v_min: c_int,
+        v_max: c_int,
+    ) -> c_int;
+    pub fn imgui_drag_float(
+        label: *const c_char,
+        label_end: *const c_char,
+        v: *mut c_float,
+        v_speed: c_float,
+        v_min: c_float,
+        v_max: c_float,
+        format: *const c_char,
+        format_end: *const c_char,
+        flags: c_int,
+    ) -> c_int;
+    pub fn imgui_drag_int(
+        label: *const c_char,
+        label_end: *const c_char,
+        v: *mut c_int,
+        v_speed: c_float,
+        v_min: c_int,
+        v_max: c_int,
+        format: *const c_char,
+        format_end: *const c_char,
+        flags: c_int,
+    ) -> c_int;
+    pub fn imgui_drag_float_range2(
+        label: *const c_char,
+        label_end: *const c_char,
+        v_current_min: *mut c_float,
+        v_current_max: *mut c_float,
+        v_speed: c_float,
+        v_min: c_float,
+        v_max: c_float,
+        format: *const c_char,
+        format_end: *const c_char,
+        format_max: *const c_char,
+        format_max_end: *const c_char,
+        flags: c_int,
+    ) -> c_int;
+    pub fn imgui_drag_int_range2(
+        label: *const c_char,
+        label_end: *const c_char,
+        v_current_min: *mut c_int,
+        v_current_max: *mut c_int,
+        v_speed: c_float,
+        v_min: c_int,
+        v_max: c_int,
+        format: *const c_char,
+        format_end: *const c_char,
+        format_max: *const c_char,
+        format_max_end: *const c_char,
+        flags: c_int,
+    ) -> c_int;
+    pub fn imgui_slider_scalar_n(
+        label: *const c_char,
+        label_end: *const c_char,
+        data_type: c_int,
+        p_data: *mut c_void,
+        components: c_int,
+        p_min: *const c_void,
+        p_max: *const c_void,
+        format: *const c_char,
+        format_end: *const c_char,
+        flags: c_int,
+    ) -> c_int;
+    pub fn imgui_v_slider_scalar(
+        label: *const c_char,
+        label_end: *const c_char,
+        width: c_float,
+        height: c_float,
+        data_type: c_int,
+        p_data: *mut c_void,
+        p_min: *const c_void,
+        p_max: *const c_void,
+        format: *const c_char,
+        format_end: *const c_char,
+        flags: c_int,
+    ) -> c_int;
+    pub fn imgui_slider_angle(
+        label: *const c_char,
+        label_end: *const c_char,
+        v_rad: *mut c_float,
+        v_degrees_min: c_float,
+        v_degrees_max: c_float,
+        format: *const c_char,
+        format_end: *const c_char,
+        flags: c_int,
+    ) -> c_int;
+    pub fn imgui_drag_scalar_n(
+        label: *const c_char,
+        label_end: *const c_char,
+        data_type: c_int,
+        p_data: *mut c_void,
+        components: c_int,
+        v_speed: c_float,
+        p_min: *const c_void,
+        p_max: *const c_void,
+        format: *const c_char,
+        format_end: *const c_char,
+        flags: c_int,
+    ) -> c_int;
+    pub fn imgui_input_scalar_n(
+        label: *const c_char,
+        label_end: *const c_char,
+        data_type: c_int,
+        p_data: *mut c_void,
+        components: c_int,
+        p_step: *const c_void,
+        p_step_fast: *const c_void,
+        format: *const c_char,
+        format_end: *const c_char,
+        flags: c_int,
+    ) -> c_int;
+    pub fn imgui_input_float(
+        label: *const c_char,
+        label_end: *const c_char,
+        v: *mut c_float,
+    ) -> c_int;
+    pub fn imgui_input_int(label: *const c_char, label_end: *const c_char, v: *mut c_int) -> c_int;
+    pub fn imgui_input_text(
+        label: *const c_char,
+        label_end: *const c_char,
+        buf: *mut c_char,
+        buf_size: usize,
+        flags: c_int,
+        callback: InputTextCallback,
+        user_data: *mut c_void,
+    ) -> c_int;
+    pub fn imgui_input_text_multiline(
+        label: *const c_char,
+        label_end: *const c_char,
+        buf: *mut c_char,
+        buf_size: usize,
+        width: c_float,
+        height: c_float,
+        flags: c_int,
+        callback: InputTextCallback,
+        user_data: *mut c_void,
+    ) -> c_int;
+    pub fn imgui_input_text_with_hint(
+        label: *const c_char,
+        label_end: *const c_char,
+        hint: *const c_char,
+        hint_end: *const c_char,
+        buf: *mut c_char,
+        buf_size: usize,
+        flags: c_int,
+        callback: InputTextCallback,
+        user_data: *mut c_void,
+    ) -> c_int;
+    pub fn imgui_input_text_callback_insert_chars(
+        data: *mut RawInputTextCallbackData,
+        pos: c_int,
+        text: *const c_char,
+        text_end: *const c_char,
+    );
+    pub fn imgui_input_text_callback_delete_chars(data: *mut RawInputTextCallbackData, pos: c_int, bytes_count: c_int);
+    // Images
+    pub fn imgui_image(
+        texture_id: u64,
+        size: *const c_float,
+        uv0: *const c_float,
+        uv1: *const c_float,
+        tint_col: *const c_float,
+        border_col: *const c_float,
+    );
+    pub fn imgui_image_button(
+        str_id: *const c_char,
+        str_id_end: *const c_char,
+        texture_id: u64,
+        size: *const c_float,
+        uv0: *const c_float,
+        uv1: *const c_float,
+        bg_col: *const c_float,
+        tint_col: *const c_float,
+    ) -> c_int;
+    pub fn imgui_color_edit3(
+        label: *const c_char,
+        label_end: *const c_char,
+        col: *mut c_float,
+        flags: c_int,
+    ) -> c_int;
+    pub fn imgui_color_edit4(
+        label: *const c_char,
+        label_end: *const c_char,
+        col: *mut c_float,
+        flags: c_int,
+    ) -> c_int;
+    pub fn imgui_color_picker3(
+        label: *const c_char,
+        label_end: *const c_char,
+        col: *mut c_float,
+        flags: c_int,
+    ) -> c_int;
+    pub fn imgui_color_picker4(
+        label: *const c_char,
+        label_end: *const c_char,
+        col: *mut c_float,
+        flags: c_int,
+        ref_col: *const c_float,
+    ) -> c_int;
+    pub fn imgui_color_button(
+        desc_id: *const c_char,
+        desc_id_end: *const c_char,
+        col: *const c_float,
+        flags: c_int,
+        width: c_float,
+        height: c_float,
+    ) -> c_int;
+    pub fn imgui_set_color_edit_options(flags: c_int);
+    pub fn imgui_same_line();
+    pub fn imgui_separator();
+    pub fn imgui_spacing();
+    pub fn imgui_dummy(width: c_float, height: c_float);
+    pub fn imgui_indent(indent_w: c_float);
+    pub fn imgui_unindent(indent_w: c_float);
+
+    // Tree nodes
+    pub fn imgui_tree_node(label: *const c_char, label_end: *const c_char) -> c_int;
+    pub fn imgui_tree_pop();
+
+    // Combo box
+    pub fn imgui_begin_combo(
+        label: *const c_char,
+        label_end: *const c_char,
+        preview_value: *const c_char,
+        preview_value_end: *const c_char,
+        flags: c_int,
+    ) -> c_int;
+    pub fn imgui_end_combo();
+    pub fn imgui_selectable(
+        label: *const c_char,
+        label_end: *const c_char,
+        selected: c_int,
+        flags: c_int,
+    ) -> c_int;
+
+    // Menu
+    pub fn imgui_begin_main_menu_bar() -> c_int;
+    pub fn imgui_end_main_menu_bar();
+    pub fn imgui_begin_menu(
+        label: *const c_char,
+        label_end: *const c_char,
+        enabled: c_int,
+    ) -> c_int;
+    pub fn imgui_end_menu();
+    pub fn imgui_menu_item(
+        label: *const c_char,
+        label_end: *const c_char,
+        shortcut: *const c_char,
+        shortcut_end: *const c_char,
+        selected: c_int,
+        enabled: c_int,
+    ) -> c_int;
+
+    // Tooltips
+    pub fn imgui_set_tooltip(text: *const c_char, text_end: *const c_char);
+    pub fn imgui_begin_tooltip() -> c_int;
+    pub fn imgui_end_tooltip();
+
+    // Popups
+    pub fn imgui_begin_popup(
+        str_id: *const c_char,
+        str_id_end: *const c_char,
+        flags: c_int,
+    ) -> c_int;
+    pub fn imgui_begin_popup_modal(
+        name: *const c_char,
+        name_end: *const c_char,
+        p_open: *mut c_int,
+        flags: c_int,
+    ) -> c_int;
+    pub fn imgui_end_popup();
+    pub fn imgui_open_popup(str_id: *const c_char, str_id_end: *const c_char);
+    pub fn imgui_close_current_popup();
+
+    // Tables
+    pub fn imgui_begin_table(
+        str_id: *const c_char,
+        str_id_end: *const c_char,
+        column: c_int,
+        flags: c_int,
+    ) -> c_int;
+    pub fn imgui_end_table();
+    pub fn imgui_table_next_row();
+    pub fn imgui_table_next_column() -> c_int;
+    pub fn imgui_table_set_column_index(column_n: c_int) -> c_int;
+    pub fn imgui_table_setup_column(
+        label: *const c_char,
+        label_end: *const c_char,
+        flags: c_int,
+        init_width_or_weight: c_float,
+    );
+    pub fn imgui_table_headers_row();
+
+    // Columns (legacy)
+    pub fn imgui_columns(count: c_int, id: *const c_char, id_end: *const c_char, border: c_int);
+    pub fn imgui_next_column();
+
+    // Style
+    pub fn imgui_push_style_color(idx: c_int, r: c_float, g: c_float, b: c_float, a: c_float);
+    pub fn imgui_pop_style_color(count: c_int);
+    pub fn imgui_push_style_var_float(idx: c_int, val: c_float);
+    pub fn imgui_push_style_var_vec2(idx: c_int, x: c_float, y: c_float);
+    pub fn imgui_pop_style_var(count: c_int);
+
+    // Persistent style
+    pub fn imgui_get_style(out: *mut Style);
+    pub fn imgui_set_style(style: *const Style);
+    pub fn imgui_style_default(out: *mut Style);
+    pub fn imgui_style_colors_dark(dst: *mut Style);
+    pub fn imgui_style_colors_light(dst: *mut Style);
+    pub fn imgui_style_colors_classic(dst: *mut Style);
+    pub fn imgui_style_scale_all_sizes(style: *mut Style, factor: c_float);
+
+    // ID stack
+    pub fn imgui_push_id_int(int_id: c_int);
+    pub fn imgui_push_id_str(str_id: *const c_char, str_id_end: *const c_char);
+    pub fn imgui_pop_id();
+
+    // Fonts
+    pub fn imgui_fonts_clear();
+    pub fn imgui_fonts_add_default(size_pixels: c_float, config: *const RawFontConfig) -> c_int;
+    pub fn imgui_fonts_add_ttf(
+        data: *const c_void,
+        data_size: c_int,
+        size_pixels: c_float,
+        config: *const RawFontConfig,
+    ) -> c_int;
+    pub fn imgui_glyph_ranges(set: c_int) -> *const u16;
+    pub fn imgui_fonts_build() -> c_int;
+    pub fn imgui_fonts_tex_data_rgba32(out_pixels: *mut *const u8, out_width: *mut c_int, out_height: *mut c_int) -> u64;
+    pub fn imgui_push_font(index: c_int);
+    pub fn imgui_pop_font();
+
+    // Utilities
+    pub fn imgui_is_item_hovered() -> c_int;
+    pub fn imgui_is_item_clicked(mouse_button: c_int) -> c_int;
+    pub fn imgui_is_item_active() -> c_int;
+    pub fn imgui_get_content_region_avail(out_size: *mut c_float);
+    pub fn imgui_get_cursor_screen_pos(out_pos: *mut c_float);
+    pub fn imgui_get_mouse_pos(out_pos: *mut c_float);
+    pub fn imgui_set_next_window_pos(x: c_float, y: c_float, cond: c_int);
+    pub fn imgui_set_next_window_size(width: c_float, height: c_float, cond: c_int);
+
+    // Offscreen render targets
+    pub fn imgui_render_target_create(width: c_int, height: c_int, out_texture: *mut c_uint) -> c_uint;
+    pub fn imgui_render_target_resize(texture: c_uint, width: c_int, height: c_int);
+    pub fn imgui_render_target_destroy(fbo: c_uint, texture: c_uint);
+    pub fn imgui_render_target_bind(fbo: c_uint, x: c_int, y: c_int, width: c_int, height: c_int, saved: *mut c_int);
+    pub fn imgui_render_target_unbind(saved: *const c_int);
+    pub fn imgui_render_target_clear(r: c_float, g: c_float, b: c_float, a: c_float);
+    pub fn imgui_render_target_lock_alpha(lock: c_int);
+    pub fn imgui_render_target_read_pixels(fbo: c_uint, width: c_int, height: c_int, out_pixels: *mut u8);
+    pub fn imgui_draw_data_framebuffer_size(out_size: *mut c_int) -> c_int;
+    pub fn imgui_opengl3_render_draw_data_to(fbo: c_uint, width: c_int, height: c_int) -> c_int;
+
+    // Demo window
+    pub fn imgui_show_demo_window(p_open: *mut c_int);
+
+    // DPI scaling
+    pub fn imgui_get_dpi_scale(window: *const GLFWwindow) -> c_float;
+    pub fn imgui_get_framebuffer_scale(window: *const GLFWwindow) -> c_float;
+}
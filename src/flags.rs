@@ -0,0 +1,344 @@
+//! Typed flags and conditions.
+//!
+//! Methods take these types only. Code still using the deprecated
+//! `window_flags`/`table_flags`/`cond` constants can call the deprecated
+//! `*_raw` methods of `Ui` and `WindowBuilder` while it migrates.
+
+use bitflags::bitflags;
+
+use crate::ImGuiError;
+
+bitflags! {
+    /// Window flags for `begin()` and `begin_popup()`.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct WindowFlags: i32 {
+        const NO_TITLE_BAR = 1 << 0;
+        const NO_RESIZE = 1 << 1;
+        const NO_MOVE = 1 << 2;
+        const NO_SCROLLBAR = 1 << 3;
+        const NO_SCROLL_WITH_MOUSE = 1 << 4;
+        const NO_COLLAPSE = 1 << 5;
+        const ALWAYS_AUTO_RESIZE = 1 << 6;
+        const NO_BACKGROUND = 1 << 7;
+        const NO_SAVED_SETTINGS = 1 << 8;
+        const NO_MOUSE_INPUTS = 1 << 9;
+        const MENU_BAR = 1 << 10;
+        const HORIZONTAL_SCROLLBAR = 1 << 11;
+        const NO_FOCUS_ON_APPEARING = 1 << 12;
+        const NO_BRING_TO_FRONT_ON_FOCUS = 1 << 13;
+        const ALWAYS_VERTICAL_SCROLLBAR = 1 << 14;
+        const ALWAYS_HORIZONTAL_SCROLLBAR = 1 << 15;
+        const NO_NAV_INPUTS = 1 << 16;
+        const NO_NAV_FOCUS = 1 << 17;
+        const UNSAVED_DOCUMENT = 1 << 18;
+        const NO_NAV = Self::NO_NAV_INPUTS.bits() | Self::NO_NAV_FOCUS.bits();
+        const NO_DECORATION = Self::NO_TITLE_BAR.bits()
+            | Self::NO_RESIZE.bits()
+            | Self::NO_SCROLLBAR.bits()
+            | Self::NO_COLLAPSE.bits();
+        const NO_INPUTS =
+            Self::NO_MOUSE_INPUTS.bits() | Self::NO_NAV_INPUTS.bits() | Self::NO_NAV_FOCUS.bits();
+    }
+}
+
+bitflags! {
+    /// Table flags for `begin_table()`.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct TableFlags: i32 {
+        const RESIZABLE = 1 << 0;
+        const REORDERABLE = 1 << 1;
+        const HIDEABLE = 1 << 2;
+        const SORTABLE = 1 << 3;
+        const NO_SAVED_SETTINGS = 1 << 4;
+        const CONTEXT_MENU_IN_BODY = 1 << 5;
+        const ROW_BG = 1 << 6;
+        const BORDERS_INNER_H = 1 << 7;
+        const BORDERS_OUTER_H = 1 << 8;
+        const BORDERS_INNER_V = 1 << 9;
+        const BORDERS_OUTER_V = 1 << 10;
+        const BORDERS_H = Self::BORDERS_INNER_H.bits() | Self::BORDERS_OUTER_H.bits();
+        const BORDERS_V = Self::BORDERS_INNER_V.bits() | Self::BORDERS_OUTER_V.bits();
+        const BORDERS_INNER = Self::BORDERS_INNER_V.bits() | Self::BORDERS_INNER_H.bits();
+        const BORDERS_OUTER = Self::BORDERS_OUTER_V.bits() | Self::BORDERS_OUTER_H.bits();
+        const BORDERS = Self::BORDERS_INNER.bits() | Self::BORDERS_OUTER.bits();
+        const NO_BORDERS_IN_BODY = 1 << 11;
+        const NO_BORDERS_IN_BODY_UNTIL_RESIZE = 1 << 12;
+        const SIZING_FIXED_FIT = 1 << 13;
+        const SIZING_FIXED_SAME = 2 << 13;
+        const SIZING_STRETCH_PROP = 3 << 13;
+        const SIZING_STRETCH_SAME = 4 << 13;
+        const NO_HOST_EXTEND_X = 1 << 16;
+        const NO_HOST_EXTEND_Y = 1 << 17;
+        const NO_KEEP_COLUMNS_VISIBLE = 1 << 18;
+        const PRECISE_WIDTHS = 1 << 19;
+        const NO_CLIP = 1 << 20;
+        const PAD_OUTER_X = 1 << 21;
+        const NO_PAD_OUTER_X = 1 << 22;
+        const NO_PAD_INNER_X = 1 << 23;
+        const SCROLL_X = 1 << 24;
+        const SCROLL_Y = 1 << 25;
+        const SORT_MULTI = 1 << 26;
+        const SORT_TRISTATE = 1 << 27;
+        const HIGHLIGHT_HOVERED_COLUMN = 1 << 28;
+    }
+}
+
+bitflags! {
+    /// Column flags for `table_setup_column()`.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct TableColumnFlags: i32 {
+        const DISABLED = 1 << 0;
+        const DEFAULT_HIDE = 1 << 1;
+        const DEFAULT_SORT = 1 << 2;
+        const WIDTH_STRETCH = 1 << 3;
+        const WIDTH_FIXED = 1 << 4;
+        const NO_RESIZE = 1 << 5;
+        const NO_REORDER = 1 << 6;
+        const NO_HIDE = 1 << 7;
+        const NO_CLIP = 1 << 8;
+        const NO_SORT = 1 << 9;
+        const NO_SORT_ASCENDING = 1 << 10;
+        const NO_SORT_DESCENDING = 1 << 11;
+        const NO_HEADER_LABEL = 1 << 12;
+        const NO_HEADER_WIDTH = 1 << 13;
+        const PREFER_SORT_ASCENDING = 1 << 14;
+        const PREFER_SORT_DESCENDING = 1 << 15;
+        const INDENT_ENABLE = 1 << 16;
+        const INDENT_DISABLE = 1 << 17;
+        const ANGLED_HEADER = 1 << 18;
+    }
+}
+
+bitflags! {
+    /// Combo box flags for `begin_combo()`.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct ComboFlags: i32 {
+        const POPUP_ALIGN_LEFT = 1 << 0;
+        const HEIGHT_SMALL = 1 << 1;
+        const HEIGHT_REGULAR = 1 << 2;
+        const HEIGHT_LARGE = 1 << 3;
+        const HEIGHT_LARGEST = 1 << 4;
+        const NO_ARROW_BUTTON = 1 << 5;
+        const NO_PREVIEW = 1 << 6;
+        const WIDTH_FIT_PREVIEW = 1 << 7;
+    }
+}
+
+bitflags! {
+    /// Selectable flags for `selectable()`.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct SelectableFlags: i32 {
+        const NO_AUTO_CLOSE_POPUPS = 1 << 0;
+        const SPAN_ALL_COLUMNS = 1 << 1;
+        const ALLOW_DOUBLE_CLICK = 1 << 2;
+        const DISABLED = 1 << 3;
+        const ALLOW_OVERLAP = 1 << 4;
+        const HIGHLIGHT = 1 << 5;
+    }
+}
+
+bitflags! {
+    /// Text input flags for `input_text()` and its variants.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct InputTextFlags: i32 {
+        /// Allow `0123456789.+-*/`.
+        const CHARS_DECIMAL = 1 << 0;
+        /// Allow `0123456789ABCDEFabcdef`.
+        const CHARS_HEXADECIMAL = 1 << 1;
+        /// Allow `0123456789.+-*/eE`.
+        const CHARS_SCIENTIFIC = 1 << 2;
+        /// Turn `a..z` into `A..Z`.
+        const CHARS_UPPERCASE = 1 << 3;
+        /// Filter out spaces and tabs.
+        const CHARS_NO_BLANK = 1 << 4;
+        /// Tab inserts a `\t` character.
+        const ALLOW_TAB_INPUT = 1 << 5;
+        /// Return true when Enter is pressed instead of on every change.
+        const ENTER_RETURNS_TRUE = 1 << 6;
+        /// Escape clears the text instead of reverting it.
+        const ESCAPE_CLEARS_ALL = 1 << 7;
+        /// In multiline mode, Enter validates and Ctrl+Enter adds a new line.
+        const CTRL_ENTER_FOR_NEW_LINE = 1 << 8;
+        const READ_ONLY = 1 << 9;
+        /// Display every character as `*` and disable copying.
+        const PASSWORD = 1 << 10;
+        const ALWAYS_OVERWRITE = 1 << 11;
+        /// Select the whole text when the widget first gets mouse focus.
+        const AUTO_SELECT_ALL = 1 << 12;
+        const PARSE_EMPTY_REF_VAL = 1 << 13;
+        const DISPLAY_EMPTY_REF_VAL = 1 << 14;
+        const NO_HORIZONTAL_SCROLL = 1 << 15;
+        const NO_UNDO_REDO = 1 << 16;
+        /// Elide the left side of text that does not fit. Single line only:
+        /// `input_text_multiline()` ignores it.
+        const ELIDE_LEFT = 1 << 17;
+        const CALLBACK_COMPLETION = 1 << 18;
+        const CALLBACK_HISTORY = 1 << 19;
+        const CALLBACK_ALWAYS = 1 << 20;
+        const CALLBACK_CHAR_FILTER = 1 << 21;
+        /// Set automatically so the `String` can grow.
+        const CALLBACK_RESIZE = 1 << 22;
+        const CALLBACK_EDIT = 1 << 23;
+    }
+}
+
+bitflags! {
+    /// Flags for `invisible_button()`.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct ButtonFlags: i32 {
+        /// React to the left mouse button (the default).
+        const MOUSE_BUTTON_LEFT = 1 << 0;
+        /// React to the right mouse button.
+        const MOUSE_BUTTON_RIGHT = 1 << 1;
+        /// React to the middle mouse button.
+        const MOUSE_BUTTON_MIDDLE = 1 << 2;
+        /// Keep keyboard navigation and tabbing enabled.
+        const ENABLE_NAV = 1 << 3;
+    }
+}
+
+bitflags! {
+    /// Flags for the color edit, picker and button widgets.
+    ///
+    /// At most one flag of each group may be set: `DISPLAY_*`, `UINT8` or
+    /// `FLOAT`, `PICKER_*`, and `INPUT_*`. The widgets ignore a group with
+    /// more than one, using the current options for it instead; `validate()`
+    /// reports it.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct ColorEditFlags: i32 {
+        /// Ignore the alpha component.
+        const NO_ALPHA = 1 << 1;
+        /// Do not open a picker when clicking the color square.
+        const NO_PICKER = 1 << 2;
+        /// Disable the options menu on right-click.
+        const NO_OPTIONS = 1 << 3;
+        /// Hide the color square next to the inputs.
+        const NO_SMALL_PREVIEW = 1 << 4;
+        /// Hide the inputs, showing only the color square.
+        const NO_INPUTS = 1 << 5;
+        /// Disable the tooltip when hovering the preview.
+        const NO_TOOLTIP = 1 << 6;
+        /// Hide the inline label.
+        const NO_LABEL = 1 << 7;
+        /// Use a small preview instead of the large one beside the picker.
+        const NO_SIDE_PREVIEW = 1 << 8;
+        /// Disable drag and drop of colors.
+        const NO_DRAG_DROP = 1 << 9;
+        /// Remove the border of `color_button()`.
+        const NO_BORDER = 1 << 10;
+        /// Show the preview opaque even when alpha is edited.
+        const ALPHA_OPAQUE = 1 << 11;
+        /// No checkerboard behind transparent colors.
+        const ALPHA_NO_BG = 1 << 12;
+        /// Show the preview half opaque, half transparent.
+        const ALPHA_PREVIEW_HALF = 1 << 13;
+        /// Show an alpha bar in the picker.
+        const ALPHA_BAR = 1 << 16;
+        /// Allow values outside 0..1. Usually combined with `FLOAT`.
+        const HDR = 1 << 19;
+        /// Show RGB inputs.
+        const DISPLAY_RGB = 1 << 20;
+        /// Show HSV inputs.
+        const DISPLAY_HSV = 1 << 21;
+        /// Show a hex input.
+        const DISPLAY_HEX = 1 << 22;
+        /// Show values as 0..255.
+        const UINT8 = 1 << 23;
+        /// Show values as 0.0..1.0 floats.
+        const FLOAT = 1 << 24;
+        /// Hue bar with a saturation/value rectangle.
+        const PICKER_HUE_BAR = 1 << 25;
+        /// Hue wheel with a saturation/value triangle.
+        const PICKER_HUE_WHEEL = 1 << 26;
+        /// The edited values are RGB.
+        const INPUT_RGB = 1 << 27;
+        /// The edited values are HSV.
+        const INPUT_HSV = 1 << 28;
+    }
+}
+
+impl ColorEditFlags {
+    /// The groups ImGui allows at most one flag of.
+    const GROUPS: [ColorEditFlags; 4] = [
+        ColorEditFlags::DISPLAY_RGB
+            .union(ColorEditFlags::DISPLAY_HSV)
+            .union(ColorEditFlags::DISPLAY_HEX),
+        ColorEditFlags::UINT8.union(ColorEditFlags::FLOAT),
+        ColorEditFlags::PICKER_HUE_BAR.union(ColorEditFlags::PICKER_HUE_WHEEL),
+        ColorEditFlags::INPUT_RGB.union(ColorEditFlags::INPUT_HSV),
+    ];
+
+    /// Fail if more than one flag of a group is set, which ImGui asserts on.
+    pub fn validate(self) -> Result<(), ImGuiError> {
+        match Self::GROUPS.iter().find(|group| (self & **group).bits().count_ones() > 1) {
+            Some(group) => Err(ImGuiError::InvalidFlags {
+                message: format!("only one of {:?} may be set", self & *group),
+            }),
+            None => Ok(()),
+        }
+    }
+
+    /// The flags without the groups that have more than one flag set.
+    pub(crate) fn sanitized(self) -> Self {
+        Self::GROUPS
+            .iter()
+            .filter(|group| (self & **group).bits().count_ones() > 1)
+            .fold(self, |flags, group| flags - *group)
+    }
+}
+
+bitflags! {
+    /// Flags for the drag and slider widgets.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct SliderFlags: i32 {
+        /// Logarithmic scale. Pair with `NO_ROUND_TO_FORMAT` when the format
+        /// shows few digits.
+        const LOGARITHMIC = 1 << 5;
+        /// Keep the full precision instead of rounding to the format.
+        const NO_ROUND_TO_FORMAT = 1 << 6;
+        /// Disable Ctrl+Click and Enter text input.
+        const NO_INPUT = 1 << 7;
+        /// Wrap from max to min and back. Drag widgets only: sliders ignore
+        /// it, and their `try_build()` rejects it.
+        const WRAP_AROUND = 1 << 8;
+        /// Clamp values typed with Ctrl+Click.
+        const CLAMP_ON_INPUT = 1 << 9;
+        /// Clamp even when min and max are both zero.
+        const CLAMP_ZERO_RANGE = 1 << 10;
+        /// Ignore the keyboard modifiers that change the drag speed.
+        const NO_SPEED_TWEAKS = 1 << 11;
+        /// Always clamp to the range, including typed values.
+        const ALWAYS_CLAMP = Self::CLAMP_ON_INPUT.bits() | Self::CLAMP_ZERO_RANGE.bits();
+    }
+}
+
+/// Condition for `set_next_window_pos()` and `set_next_window_size()`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[repr(i32)]
+pub enum Cond {
+    /// Always set the value.
+    #[default]
+    Always = 1 << 0,
+    /// Set the value once per runtime session.
+    Once = 1 << 1,
+    /// Set the value if the window has no saved data in the .ini file.
+    FirstUseEver = 1 << 2,
+    /// Set the value when the window appears after being hidden.
+    Appearing = 1 << 3,
+}
+
+impl TryFrom<i32> for Cond {
+    type Error = ImGuiError;
+
+    /// Convert an `ImGuiCond` value. `0` is `Always`, as in ImGui; values
+    /// that are not a single condition are rejected.
+    fn try_from(value: i32) -> Result<Self, Self::Error> {
+        match value {
+            0 | 1 => Ok(Cond::Always),
+            2 => Ok(Cond::Once),
+            4 => Ok(Cond::FirstUseEver),
+            8 => Ok(Cond::Appearing),
+            _ => Err(ImGuiError::InvalidCond { value }),
+        }
+    }
+}
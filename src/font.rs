@@ -0,0 +1,499 @@
+//! Font atlas description, font settings and font handles.
+
+use std::fs;
+use std::ops::RangeInclusive;
+use std::os::raw::{c_float, c_int};
+use std::path::Path;
+use std::ptr;
+use std::sync::Arc;
+
+use crate::{ffi, ImGuiError};
+
+/// Size of ImGui's built-in ProggyClean font, in pixels.
+pub const DEFAULT_FONT_SIZE: f32 = 13.0;
+
+/// Handle to a font in the atlas, returned by the `FontAtlas::add_*` methods.
+///
+/// Pass it to `Ui::push_font()`. A handle from an atlas that has since been
+/// replaced falls back to the default font.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FontId(pub(crate) i32);
+
+/// Set of characters to rasterize from a font.
+///
+/// ImGui stores characters as 16-bit values, so code points above U+FFFF
+/// are ignored.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GlyphRanges(Ranges);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Ranges {
+    BuiltIn(c_int),
+    // Inclusive pairs followed by a 0 terminator, as ImGui expects
+    Custom(Arc<[u16]>),
+}
+
+impl GlyphRanges {
+    /// Basic Latin and Latin-1 Supplement.
+    pub const DEFAULT: GlyphRanges = GlyphRanges(Ranges::BuiltIn(0));
+    /// Default plus Greek and Coptic.
+    pub const GREEK: GlyphRanges = GlyphRanges(Ranges::BuiltIn(1));
+    /// Default plus Korean characters.
+    pub const KOREAN: GlyphRanges = GlyphRanges(Ranges::BuiltIn(2));
+    /// Default plus Hiragana, Katakana, half-width forms and 2999 common ideographs.
+    pub const JAPANESE: GlyphRanges = GlyphRanges(Ranges::BuiltIn(3));
+    /// Default plus half-width forms, Hiragana, Katakana and about 21000 CJK ideographs.
+    pub const CHINESE_FULL: GlyphRanges = GlyphRanges(Ranges::BuiltIn(4));
+    /// Default plus half-width forms, Hiragana, Katakana and 2500 common simplified Chinese ideographs.
+    pub const CHINESE_SIMPLIFIED_COMMON: GlyphRanges = GlyphRanges(Ranges::BuiltIn(5));
+    /// Default plus about 400 Cyrillic characters.
+    pub const CYRILLIC: GlyphRanges = GlyphRanges(Ranges::BuiltIn(6));
+    /// Default plus Thai characters.
+    pub const THAI: GlyphRanges = GlyphRanges(Ranges::BuiltIn(7));
+    /// Default plus Vietnamese characters.
+    pub const VIETNAMESE: GlyphRanges = GlyphRanges(Ranges::BuiltIn(8));
+
+    /// Ranges of code points, e.g. `&[0xf000..=0xf8ff]` for an icon font.
+    pub fn from_ranges(ranges: &[RangeInclusive<u32>]) -> Self {
+        let mut builder = GlyphRangesBuilder::new();
+        for range in ranges {
+            builder.add_range(range.clone());
+        }
+        builder.build()
+    }
+
+    /// Pointer to the zero-terminated ImWchar pairs.
+    fn as_ptr(&self) -> *const u16 {
+        match &self.0 {
+            Ranges::BuiltIn(set) => unsafe { ffi::imgui_glyph_ranges(*set) },
+            Ranges::Custom(pairs) => pairs.as_ptr(),
+        }
+    }
+
+    /// Inclusive code point pairs.
+    fn pairs(&self) -> Vec<(u16, u16)> {
+        let mut pairs = Vec::new();
+        let mut p = self.as_ptr();
+        unsafe {
+            while *p != 0 {
+                pairs.push((*p, *p.add(1)));
+                p = p.add(2);
+            }
+        }
+        pairs
+    }
+}
+
+impl Default for GlyphRanges {
+    fn default() -> Self {
+        GlyphRanges::DEFAULT
+    }
+}
+
+/// Builds `GlyphRanges` from text, characters and other ranges.
+///
+/// ```ignore
+/// let mut builder = GlyphRangesBuilder::new();
+/// builder.add_ranges(&GlyphRanges::DEFAULT);
+/// builder.add_text("Привет, 世界");
+/// let ranges = builder.build();
+/// ```
+#[derive(Clone)]
+pub struct GlyphRangesBuilder {
+    // One bit per 16-bit code point
+    used: Box<[u64; 1024]>,
+}
+
+impl GlyphRangesBuilder {
+    /// An empty builder.
+    pub fn new() -> Self {
+        Self {
+            used: Box::new([0; 1024]),
+        }
+    }
+
+    /// Add a single character.
+    pub fn add_char(&mut self, c: char) {
+        self.add_range(c as u32..=c as u32);
+    }
+
+    /// Add every character of `text`.
+    pub fn add_text(&mut self, text: &str) {
+        for c in text.chars() {
+            self.add_char(c);
+        }
+    }
+
+    /// Add an inclusive range of code points.
+    pub fn add_range(&mut self, range: RangeInclusive<u32>) {
+        let start = (*range.start()).max(1);
+        let end = (*range.end()).min(u16::MAX as u32);
+        for code in start..=end {
+            self.used[code as usize / 64] |= 1 << (code % 64);
+        }
+    }
+
+    /// Add all characters of `ranges`.
+    pub fn add_ranges(&mut self, ranges: &GlyphRanges) {
+        for (start, end) in ranges.pairs() {
+            self.add_range(start as u32..=end as u32);
+        }
+    }
+
+    /// Collapse the added characters into ranges.
+    pub fn build(&self) -> GlyphRanges {
+        let mut pairs = Vec::new();
+        let mut code = 1;
+        while code <= u16::MAX as u32 {
+            if self.contains(code) {
+                let start = code;
+                while code < u16::MAX as u32 && self.contains(code + 1) {
+                    code += 1;
+                }
+                pairs.extend([start as u16, code as u16]);
+            }
+            code += 1;
+        }
+        pairs.push(0);
+        GlyphRanges(Ranges::Custom(pairs.into()))
+    }
+
+    fn contains(&self, code: u32) -> bool {
+        self.used[code as usize / 64] & (1 << (code % 64)) != 0
+    }
+}
+
+impl Default for GlyphRangesBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Rasterization settings for a font (`ImFontConfig`).
+///
+/// Merging an icon font into the previous font:
+///
+/// ```ignore
+/// let mut fonts = FontAtlas::new();
+/// let body = fonts.add_ttf_file("Roboto-Regular.ttf", 16.0)?;
+/// let icons = FontConfig {
+///     merge_mode: true,
+///     glyph_ranges: GlyphRanges::from_ranges(&[0xf000..=0xf8ff]),
+///     glyph_min_advance_x: 16.0,
+///     ..Default::default()
+/// };
+/// fonts.add_ttf_file_with("fa-solid-900.ttf", 16.0, &icons)?;
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct FontConfig {
+    /// Merge the glyphs into the previously added font instead of creating a
+    /// new one. Ignored for the first font of an atlas.
+    pub merge_mode: bool,
+    /// Characters to rasterize.
+    pub glyph_ranges: GlyphRanges,
+    /// Horizontal oversampling; 0 picks 1 or 2 depending on the size.
+    pub oversample_h: i32,
+    /// Vertical oversampling; 0 picks 1.
+    pub oversample_v: i32,
+    /// Round glyph advances to whole pixels.
+    pub pixel_snap_h: bool,
+    /// Offset applied to every glyph, in pixels.
+    pub glyph_offset: [f32; 2],
+    /// Extra horizontal spacing between glyphs, in pixels.
+    pub glyph_extra_spacing_x: f32,
+    /// Minimum glyph advance, e.g. to align icons.
+    pub glyph_min_advance_x: f32,
+    /// Maximum glyph advance.
+    pub glyph_max_advance_x: f32,
+    /// Brighten (above 1.0) or darken (below 1.0) the rasterized glyphs.
+    pub rasterizer_multiply: f32,
+}
+
+impl Default for FontConfig {
+    fn default() -> Self {
+        Self {
+            merge_mode: false,
+            glyph_ranges: GlyphRanges::DEFAULT,
+            oversample_h: 0,
+            oversample_v: 0,
+            pixel_snap_h: false,
+            glyph_offset: [0.0, 0.0],
+            glyph_extra_spacing_x: 0.0,
+            glyph_min_advance_x: 0.0,
+            glyph_max_advance_x: f32::MAX,
+            rasterizer_multiply: 1.0,
+        }
+    }
+}
+
+impl FontConfig {
+    fn raw(&self, scale: f32) -> RawFontConfig {
+        RawFontConfig {
+            merge_mode: self.merge_mode as c_int,
+            pixel_snap_h: self.pixel_snap_h as c_int,
+            oversample_h: self.oversample_h,
+            oversample_v: self.oversample_v,
+            glyph_offset_x: self.glyph_offset[0] * scale,
+            glyph_offset_y: self.glyph_offset[1] * scale,
+            glyph_extra_spacing_x: self.glyph_extra_spacing_x * scale,
+            glyph_min_advance_x: self.glyph_min_advance_x * scale,
+            glyph_max_advance_x: self.glyph_max_advance_x,
+            rasterizer_multiply: self.rasterizer_multiply,
+            glyph_ranges: self.glyph_ranges.as_ptr(),
+        }
+    }
+}
+
+/// Mirrors `ImGuiWrapperFontConfig` in `imgui_wrapper.h`.
+#[repr(C)]
+pub(crate) struct RawFontConfig {
+    merge_mode: c_int,
+    pixel_snap_h: c_int,
+    oversample_h: c_int,
+    oversample_v: c_int,
+    glyph_offset_x: c_float,
+    glyph_offset_y: c_float,
+    glyph_extra_spacing_x: c_float,
+    glyph_min_advance_x: c_float,
+    glyph_max_advance_x: c_float,
+    rasterizer_multiply: c_float,
+    glyph_ranges: *const u16,
+}
+
+#[derive(Clone)]
+enum FontData {
+    Static(&'static [u8]),
+    Owned(Arc<[u8]>),
+}
+
+impl FontData {
+    fn bytes(&self) -> &[u8] {
+        match self {
+            FontData::Static(data) => data,
+            FontData::Owned(data) => data,
+        }
+    }
+}
+
+#[derive(Clone)]
+enum FontSource {
+    Default,
+    Ttf(FontData),
+}
+
+#[derive(Clone)]
+struct FontEntry {
+    source: FontSource,
+    size_pixels: f32,
+    config: Option<FontConfig>,
+}
+
+/// The set of fonts to rasterize, applied with `ImGui::set_fonts()`.
+///
+/// The first font added is the default font.
+///
+/// ```ignore
+/// let mut fonts = FontAtlas::new();
+/// let body = fonts.add_ttf_bytes(include_bytes!("Roboto-Regular.ttf"), 16.0)?;
+/// let mono = fonts.add_ttf_file("assets/JetBrainsMono.ttf", 14.0)?;
+/// imgui.set_fonts(fonts)?;
+///
+/// // in a frame
+/// ui.with_font(mono, || ui.text("fn main() {}"));
+/// ```
+#[derive(Clone, Default)]
+pub struct FontAtlas {
+    fonts: Vec<FontEntry>,
+    // Fonts created so far; merged entries do not create one
+    font_count: i32,
+}
+
+impl FontAtlas {
+    /// An empty atlas. Building it yields only the built-in font.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add the built-in ProggyClean font at the given pixel size.
+    pub fn add_default(&mut self, size_pixels: f32) -> FontId {
+        self.push(FontSource::Default, size_pixels, None)
+    }
+
+    /// Add the built-in font with custom settings.
+    pub fn add_default_with(&mut self, size_pixels: f32, config: &FontConfig) -> FontId {
+        self.push(FontSource::Default, size_pixels, Some(config.clone()))
+    }
+
+    /// Add a TTF or OTF font embedded in the binary.
+    pub fn add_ttf_bytes(&mut self, data: &'static [u8], size_pixels: f32) -> Result<FontId, ImGuiError> {
+        check_font_data(data)?;
+        Ok(self.push(FontSource::Ttf(FontData::Static(data)), size_pixels, None))
+    }
+
+    /// Add a TTF or OTF font embedded in the binary, with custom settings.
+    pub fn add_ttf_bytes_with(
+        &mut self,
+        data: &'static [u8],
+        size_pixels: f32,
+        config: &FontConfig,
+    ) -> Result<FontId, ImGuiError> {
+        check_font_data(data)?;
+        Ok(self.push(FontSource::Ttf(FontData::Static(data)), size_pixels, Some(config.clone())))
+    }
+
+    /// Add a TTF or OTF font read from `path`.
+    pub fn add_ttf_file(&mut self, path: impl AsRef<Path>, size_pixels: f32) -> Result<FontId, ImGuiError> {
+        let data = read_font_file(path.as_ref())?;
+        Ok(self.push(FontSource::Ttf(FontData::Owned(data)), size_pixels, None))
+    }
+
+    /// Add a TTF or OTF font read from `path`, with custom settings.
+    pub fn add_ttf_file_with(
+        &mut self,
+        path: impl AsRef<Path>,
+        size_pixels: f32,
+        config: &FontConfig,
+    ) -> Result<FontId, ImGuiError> {
+        let data = read_font_file(path.as_ref())?;
+        Ok(self.push(FontSource::Ttf(FontData::Owned(data)), size_pixels, Some(config.clone())))
+    }
+
+    /// Number of fonts in the atlas.
+    pub fn len(&self) -> usize {
+        self.fonts.len()
+    }
+
+    /// Returns true if no font has been added.
+    pub fn is_empty(&self) -> bool {
+        self.fonts.is_empty()
+    }
+
+    fn push(&mut self, source: FontSource, size_pixels: f32, mut config: Option<FontConfig>) -> FontId {
+        match &mut config {
+            Some(config) if config.merge_mode && self.font_count > 0 => {}
+            Some(config) => {
+                config.merge_mode = false;
+                self.font_count += 1;
+            }
+            None => self.font_count += 1,
+        }
+        self.fonts.push(FontEntry {
+            source,
+            size_pixels,
+            config,
+        });
+        FontId(self.font_count - 1)
+    }
+
+    /// Replace the ImGui atlas with these fonts, rasterized at `scale` times
+    /// their size. An empty atlas yields the built-in font.
+    ///
+    /// The font data and glyph ranges must stay alive as long as ImGui uses
+    /// the atlas.
+    pub(crate) fn build(&self, scale: f32) -> Result<(), ImGuiError> {
+        unsafe {
+            ffi::imgui_fonts_clear();
+            if self.fonts.is_empty() {
+                ffi::imgui_fonts_add_default(DEFAULT_FONT_SIZE * scale, ptr::null());
+            }
+            for font in &self.fonts {
+                let size_pixels = font.size_pixels * scale;
+                let raw = font.config.as_ref().map(|config| config.raw(scale));
+                let config = raw.as_ref().map_or(ptr::null(), |raw| raw as *const RawFontConfig);
+                let index = match &font.source {
+                    FontSource::Default => ffi::imgui_fonts_add_default(size_pixels, config),
+                    FontSource::Ttf(data) => {
+                        let bytes = data.bytes();
+                        ffi::imgui_fonts_add_ttf(
+                            bytes.as_ptr().cast(),
+                            bytes.len() as i32,
+                            size_pixels,
+                            config,
+                        )
+                    }
+                };
+                if index < 0 {
+                    return Err(ImGuiError::Font {
+                        message: "ImGui rejected the font".to_string(),
+                    });
+                }
+            }
+            if ffi::imgui_fonts_build() == 0 {
+                return Err(ImGuiError::Font {
+                    message: "failed to build the font atlas".to_string(),
+                });
+            }
+        }
+        Ok(())
+    }
+}
+
+fn read_font_file(path: &Path) -> Result<Arc<[u8]>, ImGuiError> {
+    let data = fs::read(path).map_err(|e| ImGuiError::Font {
+        message: format!("{}: {}", path.display(), e),
+    })?;
+    check_font_data(&data)?;
+    Ok(data.into())
+}
+
+/// Reject data that is not a TrueType/OpenType font before ImGui asserts on it.
+fn check_font_data(data: &[u8]) -> Result<(), ImGuiError> {
+    match data.get(..4) {
+        Some([0, 1, 0, 0]) | Some(b"OTTO") | Some(b"true") | Some(b"ttcf") => {}
+        _ => {
+            return Err(ImGuiError::Font {
+                message: "not a TrueType or OpenType font".to_string(),
+            })
+        }
+    }
+    if data.len() > i32::MAX as usize {
+        return Err(ImGuiError::Font {
+            message: "font data is too large".to_string(),
+        });
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn build(ranges: &[RangeInclusive<u32>]) -> Vec<(u16, u16)> {
+        GlyphRanges::from_ranges(ranges).pairs()
+    }
+
+    #[test]
+    fn overlapping_ranges_merge() {
+        assert_eq!(build(&[0x20..=0x7f, 0x60..=0xff]), [(0x20, 0xff)]);
+        assert_eq!(build(&[0x400..=0x4ff, 0x410..=0x420]), [(0x400, 0x4ff)]);
+    }
+
+    #[test]
+    fn adjacent_ranges_merge() {
+        assert_eq!(build(&[0x20..=0x7f, 0x80..=0xff]), [(0x20, 0xff)]);
+        assert_eq!(build(&[0x80..=0xff, 0x20..=0x7f]), [(0x20, 0xff)]);
+        assert_eq!(build(&[0x20..=0x7f, 0x81..=0xff]), [(0x20, 0x7f), (0x81, 0xff)]);
+    }
+
+    #[test]
+    fn single_code_point() {
+        let mut builder = GlyphRangesBuilder::new();
+        builder.add_char('€');
+        assert_eq!(builder.build().pairs(), [(0x20ac, 0x20ac)]);
+        assert_eq!(build(&[0xffff..=0xffff]), [(0xffff, 0xffff)]);
+    }
+
+    #[test]
+    fn empty_builder_builds_no_ranges() {
+        let ranges = GlyphRangesBuilder::new().build();
+        assert_eq!(ranges.pairs(), []);
+        assert_eq!(ranges, GlyphRanges(Ranges::Custom(Arc::from([0u16]))));
+    }
+
+    #[test]
+    fn code_points_outside_imwchar_are_dropped() {
+        assert_eq!(build(&[0..=0]), []);
+        assert_eq!(build(&[0x1f600..=0x1f64f]), []);
+        assert_eq!(build(&[0xfff0..=0x10010]), [(0xfff0, 0xffff)]);
+    }
+}
@@ -0,0 +1,139 @@
+//! Validation of the printf formats passed to numeric widgets.
+//!
+//! ImGui hands the format straight to `vsnprintf` along with the value, so
+//! a conversion that does not match the value's type, such as `%s` or `%ld`
+//! for an `i32`, reads whatever happens to be in the argument registers.
+//! Formats are checked here before they reach C; widgets given an invalid
+//! format fall back to ImGui's default for the type.
+
+use crate::data_type::{DOUBLE, FLOAT, S64, U64};
+use crate::ImGuiError;
+
+/// Fail if `format` is not a safe printf format for values of `data_type`.
+///
+/// A valid format has at most one conversion besides `%%`, without `*`
+/// width or precision, and with a conversion and length modifier matching
+/// the type: `f`, `F`, `e`, `E`, `g`, `G`, `a` or `A` for floats, and `d`,
+/// `i`, `u`, `o`, `x` or `X` for integers, with `ll` for 64-bit integers.
+pub(crate) fn check_format(format: &str, data_type: i32) -> Result<(), ImGuiError> {
+    // C sees the format up to its first NUL byte
+    let format = format.split('\0').next().unwrap_or_default();
+    let bytes = format.as_bytes();
+    let invalid = |message: &str| ImGuiError::InvalidFormat {
+        format: format.to_string(),
+        message: message.to_string(),
+    };
+
+    let mut i = 0;
+    let mut found = false;
+    while i < bytes.len() {
+        if bytes[i] != b'%' {
+            i += 1;
+            continue;
+        }
+        i += 1;
+        if bytes.get(i) == Some(&b'%') {
+            i += 1;
+            continue;
+        }
+        if found {
+            return Err(invalid("more than one conversion"));
+        }
+        found = true;
+
+        while matches!(bytes.get(i), Some(b'-' | b'+' | b' ' | b'#' | b'0' | b'\'')) {
+            i += 1;
+        }
+        while bytes.get(i).is_some_and(u8::is_ascii_digit) {
+            i += 1;
+        }
+        if bytes.get(i) == Some(&b'.') {
+            i += 1;
+            while bytes.get(i).is_some_and(u8::is_ascii_digit) {
+                i += 1;
+            }
+        }
+        if bytes.get(i) == Some(&b'*') {
+            return Err(invalid("`*` width and precision are not supported"));
+        }
+        let length_start = i;
+        while matches!(bytes.get(i), Some(b'h' | b'l' | b'L' | b'q' | b'j' | b'z' | b't' | b'I')) {
+            i += 1;
+        }
+        let length = &format[length_start..i];
+        let conversion = match bytes.get(i) {
+            Some(&c) => c,
+            None => return Err(invalid("incomplete conversion")),
+        };
+        i += 1;
+
+        let valid = match data_type {
+            FLOAT | DOUBLE => matches!(length, "" | "l") && b"fFeEgGaA".contains(&conversion),
+            S64 | U64 => length == "ll" && b"diuoxX".contains(&conversion),
+            _ => matches!(length, "" | "h" | "hh") && b"diuoxX".contains(&conversion),
+        };
+        if !valid {
+            return Err(invalid(match data_type {
+                FLOAT | DOUBLE => "expected a float conversion such as `%.3f`",
+                S64 | U64 => "expected a 64-bit integer conversion such as `%lld`",
+                _ => "expected an integer conversion such as `%d`",
+            }));
+        }
+    }
+    Ok(())
+}
+
+/// `format` if it is valid for `data_type`, otherwise `None` so that ImGui
+/// uses its default format.
+pub(crate) fn valid_format(format: Option<&str>, data_type: i32) -> Option<&str> {
+    format.filter(|format| check_format(format, data_type).is_ok())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data_type::{S32, S64};
+
+    #[test]
+    fn accepts_matching_formats() {
+        let cases = [
+            ("%.3f", FLOAT),
+            ("%.3f", DOUBLE),
+            ("%d px", S32),
+            ("%lld", S64),
+            ("%%", S32),
+            ("%%", FLOAT),
+            ("100%% at %d", S32),
+            ("", S32),
+            ("", FLOAT),
+        ];
+        for (format, data_type) in cases {
+            assert!(check_format(format, data_type).is_ok(), "{:?} for type {}", format, data_type);
+        }
+    }
+
+    #[test]
+    fn rejects_unsafe_formats() {
+        let cases = [
+            ("%s", S32),
+            ("%s", FLOAT),
+            ("%n", S32),
+            ("%d %d", S32),
+            ("%.1f / %.1f", FLOAT),
+            ("%d", FLOAT),
+            ("%f", S32),
+            ("%d", S64),
+            ("%*d", S32),
+            ("value %", S32),
+            ("%.2", FLOAT),
+        ];
+        for (format, data_type) in cases {
+            assert!(
+                matches!(check_format(format, data_type), Err(ImGuiError::InvalidFormat { .. })),
+                "{:?} for type {}",
+                format,
+                data_type
+            );
+        }
+    }
+}
@@ -0,0 +1,304 @@
+//! Text input backed by a Rust `String`.
+//!
+//! The string's bytes are handed to ImGui as a NUL-terminated buffer. When
+//! the text outgrows it, ImGui's resize callback grows the `Vec` and the
+//! text is truncated back to its length once the widget returns. Other
+//! callback events are forwarded to a Rust closure as an
+//! `InputTextCallbackData` view.
+
+use std::any::Any;
+use std::mem;
+use std::ops::Range;
+use std::os::raw::{c_char, c_int, c_void};
+use std::panic::{self, AssertUnwindSafe};
+use std::slice;
+
+use crate::string::str_range;
+use crate::{ffi, InputTextFlags};
+
+/// Mirrors `ImGuiInputTextCallbackData`.
+#[repr(C)]
+pub(crate) struct RawInputTextCallbackData {
+    ctx: *mut c_void,
+    event_flag: c_int,
+    flags: c_int,
+    user_data: *mut c_void,
+    event_char: u16,
+    event_key: c_int,
+    buf: *mut c_char,
+    buf_text_len: c_int,
+    buf_size: c_int,
+    buf_dirty: bool,
+    cursor_pos: c_int,
+    selection_start: c_int,
+    selection_end: c_int,
+}
+
+// Must match the `static_assert` in `imgui_wrapper.cpp`.
+const _: () = assert!(
+    mem::size_of::<RawInputTextCallbackData>() == if cfg!(target_pointer_width = "64") { 64 } else { 52 }
+);
+
+pub(crate) type InputTextCallback = unsafe extern "C" fn(data: *mut RawInputTextCallbackData) -> c_int;
+
+/// `ImGuiKey_UpArrow`, reported with history events.
+const KEY_UP_ARROW: c_int = 515;
+
+/// Why an input text callback was called.
+///
+/// Each event is only delivered if the matching `CALLBACK_*` flag is set.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum InputTextEvent {
+    /// Tab was pressed (`CALLBACK_COMPLETION`).
+    Completion,
+    /// Up or down was pressed (`CALLBACK_HISTORY`).
+    History(HistoryDirection),
+    /// A character is about to be inserted (`CALLBACK_CHAR_FILTER`).
+    CharFilter,
+    /// The text was edited (`CALLBACK_EDIT`).
+    Edit,
+    /// Called every frame while the widget is active (`CALLBACK_ALWAYS`).
+    Always,
+}
+
+/// Arrow key of a history event.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum HistoryDirection {
+    Up,
+    Down,
+}
+
+/// The edit state passed to an input text callback.
+///
+/// Positions are byte offsets into `text()`. Edits made with
+/// `insert_chars()` and `delete_chars()` are applied to the widget when the
+/// callback returns. During `CharFilter` events only the event character is
+/// available and the text is empty.
+pub struct InputTextCallbackData<'a> {
+    raw: &'a mut RawInputTextCallbackData,
+}
+
+impl InputTextCallbackData<'_> {
+    /// The event being handled.
+    pub fn event(&self) -> InputTextEvent {
+        let flag = InputTextFlags::from_bits_retain(self.raw.event_flag);
+        if flag == InputTextFlags::CALLBACK_COMPLETION {
+            InputTextEvent::Completion
+        } else if flag == InputTextFlags::CALLBACK_HISTORY {
+            InputTextEvent::History(if self.raw.event_key == KEY_UP_ARROW {
+                HistoryDirection::Up
+            } else {
+                HistoryDirection::Down
+            })
+        } else if flag == InputTextFlags::CALLBACK_CHAR_FILTER {
+            InputTextEvent::CharFilter
+        } else if flag == InputTextFlags::CALLBACK_EDIT {
+            InputTextEvent::Edit
+        } else {
+            InputTextEvent::Always
+        }
+    }
+
+    /// The flags the widget was called with.
+    pub fn flags(&self) -> InputTextFlags {
+        InputTextFlags::from_bits_retain(self.raw.flags)
+    }
+
+    /// The character being inserted, during `CharFilter` events.
+    pub fn event_char(&self) -> Option<char> {
+        match self.raw.event_char {
+            0 => None,
+            c => char::from_u32(c as u32),
+        }
+    }
+
+    /// Replace the character being inserted, or drop it with `None`.
+    ///
+    /// Characters above U+FFFF cannot be inserted and are dropped.
+    pub fn set_event_char(&mut self, c: Option<char>) {
+        self.raw.event_char = c.and_then(|c| u16::try_from(c as u32).ok()).unwrap_or(0);
+    }
+
+    /// The current text.
+    pub fn text(&self) -> &str {
+        if self.raw.buf.is_null() {
+            return "";
+        }
+        let bytes = unsafe { slice::from_raw_parts(self.raw.buf as *const u8, self.raw.buf_text_len as usize) };
+        std::str::from_utf8(bytes).unwrap_or_default()
+    }
+
+    /// Cursor position.
+    pub fn cursor_pos(&self) -> usize {
+        self.raw.cursor_pos as usize
+    }
+
+    /// Move the cursor, clamped to the text length.
+    pub fn set_cursor_pos(&mut self, pos: usize) {
+        self.raw.cursor_pos = self.clamp(pos);
+    }
+
+    /// Selected range; empty when nothing is selected.
+    pub fn selection(&self) -> Range<usize> {
+        let (a, b) = (self.raw.selection_start as usize, self.raw.selection_end as usize);
+        a.min(b)..a.max(b)
+    }
+
+    /// Select `range`, clamped to the text length.
+    pub fn set_selection(&mut self, range: Range<usize>) {
+        self.raw.selection_start = self.clamp(range.start);
+        self.raw.selection_end = self.clamp(range.end);
+    }
+
+    /// Returns true if some text is selected.
+    pub fn has_selection(&self) -> bool {
+        self.raw.selection_start != self.raw.selection_end
+    }
+
+    /// Select the whole text.
+    pub fn select_all(&mut self) {
+        self.raw.selection_start = 0;
+        self.raw.selection_end = self.raw.buf_text_len;
+    }
+
+    /// Clear the selection.
+    pub fn clear_selection(&mut self) {
+        self.raw.selection_start = self.raw.buf_text_len;
+        self.raw.selection_end = self.raw.buf_text_len;
+    }
+
+    /// Insert `text` at byte offset `pos`. The buffer grows as needed.
+    ///
+    /// # Panics
+    /// Panics if `pos` is past the end of the text or not on a character boundary.
+    pub fn insert_chars(&mut self, pos: usize, text: &str) {
+        self.check_boundary(pos);
+        if self.raw.buf.is_null() {
+            return;
+        }
+        let (text, text_end) = str_range(text);
+        unsafe { ffi::imgui_input_text_callback_insert_chars(self.raw, pos as c_int, text, text_end) };
+    }
+
+    /// Delete the bytes in `range`.
+    ///
+    /// # Panics
+    /// Panics if the range is out of bounds or not on character boundaries.
+    pub fn delete_chars(&mut self, range: Range<usize>) {
+        self.check_boundary(range.start);
+        self.check_boundary(range.end);
+        if self.raw.buf.is_null() || range.is_empty() {
+            return;
+        }
+        let count = (range.end - range.start) as c_int;
+        unsafe { ffi::imgui_input_text_callback_delete_chars(self.raw, range.start as c_int, count) };
+    }
+
+    /// Replace the whole text, e.g. with a history entry.
+    pub fn set_text(&mut self, text: &str) {
+        let len = self.text().len();
+        self.delete_chars(0..len);
+        self.insert_chars(0, text);
+    }
+
+    fn clamp(&self, pos: usize) -> c_int {
+        pos.min(self.raw.buf_text_len as usize) as c_int
+    }
+
+    fn check_boundary(&self, pos: usize) {
+        let text = self.text();
+        assert!(
+            text.is_char_boundary(pos),
+            "position {} is not a character boundary of a {}-byte text",
+            pos,
+            text.len()
+        );
+    }
+}
+
+/// Closure state reached through the callback's user data.
+struct EditState<'a, 'c> {
+    buf: &'a mut Vec<u8>,
+    callback: Option<&'c mut dyn FnMut(&mut InputTextCallbackData)>,
+    panic: Option<Box<dyn Any + Send>>,
+}
+
+/// Edit `text` through `widget`, which receives the buffer, its size, the
+/// flags, the callback and its user data, and returns ImGui's result.
+///
+/// A panic in `callback` is caught at the FFI boundary and resumed once the
+/// widget has returned.
+pub(crate) fn edit_string<F>(
+    text: &mut String,
+    flags: InputTextFlags,
+    callback: Option<&mut dyn FnMut(&mut InputTextCallbackData)>,
+    widget: F,
+) -> bool
+where
+    F: FnOnce(*mut c_char, usize, i32, InputTextCallback, *mut c_void) -> bool,
+{
+    let flags = flags | InputTextFlags::CALLBACK_RESIZE;
+    // Safety: the bytes are checked for UTF-8 before the `String` is used again
+    let buf = unsafe { text.as_mut_vec() };
+
+    // ImGui only sees the text up to the first NUL byte
+    if let Some(nul) = buf.iter().position(|&b| b == 0) {
+        buf.truncate(nul);
+    }
+    // Room for the terminator plus some typing before the first resize
+    let len = buf.len();
+    buf.resize(len + 1 + len.max(32), 0);
+
+    let ptr = buf.as_mut_ptr() as *mut c_char;
+    let size = buf.len();
+    let mut state = EditState {
+        buf,
+        callback,
+        panic: None,
+    };
+    let changed = widget(
+        ptr,
+        size,
+        flags.bits(),
+        input_text_callback,
+        &mut state as *mut EditState as *mut c_void,
+    );
+
+    let buf = state.buf;
+    let len = buf.iter().position(|&b| b == 0).unwrap_or(buf.len());
+    buf.truncate(len);
+    if std::str::from_utf8(buf).is_err() {
+        let fixed = String::from_utf8_lossy(buf).into_owned();
+        *buf = fixed.into_bytes();
+    }
+    if let Some(payload) = state.panic {
+        panic::resume_unwind(payload);
+    }
+    changed
+}
+
+unsafe extern "C" fn input_text_callback(data: *mut RawInputTextCallbackData) -> c_int {
+    let data = &mut *data;
+    let state = &mut *(data.user_data as *mut EditState);
+
+    if data.event_flag == InputTextFlags::CALLBACK_RESIZE.bits() {
+        // ImGui copies the new text in after the callback returns
+        state.buf.resize(data.buf_size as usize, 0);
+        data.buf = state.buf.as_mut_ptr() as *mut c_char;
+        data.buf_size = state.buf.len() as c_int;
+        return 0;
+    }
+
+    let callback = match (&mut state.callback, &state.panic) {
+        (Some(callback), None) => callback,
+        _ => return 0,
+    };
+    let result = panic::catch_unwind(AssertUnwindSafe(|| {
+        callback(&mut InputTextCallbackData { raw: data });
+    }));
+    if let Err(payload) = result {
+        state.panic = Some(payload);
+    }
+    // A dropped character is reported through `event_char`
+    0
+}
@@ -0,0 +1,165 @@
+//! Keyboard keys and mouse buttons for feeding input to ImGui manually.
+
+/// A keyboard key (`ImGuiKey`), for `ImGui::add_key_event()`.
+///
+/// Keys are named after their position on a US layout; typed text is sent
+/// separately with `add_input_character()`. The `Mod*` values are the
+/// modifier state ImGui tracks next to the physical modifier keys.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(i32)]
+pub enum Key {
+    Tab = 512,
+    LeftArrow,
+    RightArrow,
+    UpArrow,
+    DownArrow,
+    PageUp,
+    PageDown,
+    Home,
+    End,
+    Insert,
+    Delete,
+    Backspace,
+    Space,
+    Enter,
+    Escape,
+    LeftCtrl,
+    LeftShift,
+    LeftAlt,
+    LeftSuper,
+    RightCtrl,
+    RightShift,
+    RightAlt,
+    RightSuper,
+    Menu,
+    Num0,
+    Num1,
+    Num2,
+    Num3,
+    Num4,
+    Num5,
+    Num6,
+    Num7,
+    Num8,
+    Num9,
+    A,
+    B,
+    C,
+    D,
+    E,
+    F,
+    G,
+    H,
+    I,
+    J,
+    K,
+    L,
+    M,
+    N,
+    O,
+    P,
+    Q,
+    R,
+    S,
+    T,
+    U,
+    V,
+    W,
+    X,
+    Y,
+    Z,
+    F1,
+    F2,
+    F3,
+    F4,
+    F5,
+    F6,
+    F7,
+    F8,
+    F9,
+    F10,
+    F11,
+    F12,
+    F13,
+    F14,
+    F15,
+    F16,
+    F17,
+    F18,
+    F19,
+    F20,
+    F21,
+    F22,
+    F23,
+    F24,
+    Apostrophe,
+    Comma,
+    Minus,
+    Period,
+    Slash,
+    Semicolon,
+    Equal,
+    LeftBracket,
+    Backslash,
+    RightBracket,
+    GraveAccent,
+    CapsLock,
+    ScrollLock,
+    NumLock,
+    PrintScreen,
+    Pause,
+    Keypad0,
+    Keypad1,
+    Keypad2,
+    Keypad3,
+    Keypad4,
+    Keypad5,
+    Keypad6,
+    Keypad7,
+    Keypad8,
+    Keypad9,
+    KeypadDecimal,
+    KeypadDivide,
+    KeypadMultiply,
+    KeypadSubtract,
+    KeypadAdd,
+    KeypadEnter,
+    KeypadEqual,
+    AppBack,
+    AppForward,
+    /// Ctrl held (Cmd on macOS).
+    ModCtrl = 1 << 12,
+    /// Shift held.
+    ModShift = 1 << 13,
+    /// Alt or Option held.
+    ModAlt = 1 << 14,
+    /// Windows/Super held (Ctrl on macOS).
+    ModSuper = 1 << 15,
+}
+
+/// A mouse button (`ImGuiMouseButton`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(i32)]
+pub enum MouseButton {
+    Left = 0,
+    Right = 1,
+    Middle = 2,
+    /// First extra button, often "back".
+    Extra1 = 3,
+    /// Second extra button, often "forward".
+    Extra2 = 4,
+}
+
+impl MouseButton {
+    /// The button with ImGui index `index`, or `None` outside `0..5`.
+    pub(crate) fn from_index(index: i32) -> Option<Self> {
+        match index {
+            0 => Some(MouseButton::Left),
+            1 => Some(MouseButton::Right),
+            2 => Some(MouseButton::Middle),
+            3 => Some(MouseButton::Extra1),
+            4 => Some(MouseButton::Extra2),
+            _ => None,
+        }
+    }
+}
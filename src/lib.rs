@@ -2,6 +2,18 @@
 //!
 //! This crate provides Dear ImGui support on top of wilhelm_renderer.
 //!
+//! Each frame starts with `ImGui::new_frame()`, which returns a `Ui` handle
+//! carrying all widget methods. The frame ends when the `Ui` is consumed by
+//! `Ui::render()`, so widgets can only be submitted inside a frame.
+//!
+//! # Strings
+//!
+//! Labels and text are passed to the C wrapper as pointer ranges without
+//! allocating. A string is cut at its first NUL byte, as a C string would
+//! be, rather than panicking. Methods that commonly display user data
+//! (`text`, `button`, `selectable`, `tree_node`, `set_tooltip`) have `try_*`
+//! variants that return `ImGuiError::InteriorNul` instead.
+//!
 //! # Example
 //!
 //! ```ignore
@@ -11,17 +23,15 @@
 //! let window = Window::new("ImGui Example", 800, 600, Color::from_rgb(0.1, 0.1, 0.1));
 //! let mut app = App::new(window);
 //!
-//! let imgui = ImGui::new(app.window.glfw_window_ptr());
-//!
-//! app.on_pre_render(move |_shapes, _renderer| {
-//!     imgui.new_frame();
-//!     imgui.begin("Debug", None, 0);
-//!     imgui.text("Hello, ImGui!");
-//!     imgui.end();
-//! });
+//! let mut imgui = ImGui::new(app.window.glfw_window_ptr(), true)
+//!     .expect("failed to initialize ImGui");
 //!
 //! app.on_render(move |_renderer| {
-//!     imgui.render();
+//!     let ui = imgui.new_frame();
+//!     ui.window("Debug").build(|| {
+//!         ui.text("Hello, ImGui!");
+//!     });
+//!     ui.render();
 //! });
 //!
 //! app.run();
@@ -31,153 +41,63 @@ pub use wilhelm_renderer;
 pub use wilhelm_renderer::core::GLFWwindow;
 
 use std::ffi::CString;
-use std::ptr;
-
-// FFI declarations for the C wrapper
-mod ffi {
-    use std::os::raw::{c_char, c_float, c_int, c_void};
-    use wilhelm_renderer::core::GLFWwindow;
-
-    unsafe extern "C" {
-        // Context management
-        pub fn imgui_create_context() -> *mut c_void;
-        pub fn imgui_destroy_context(ctx: *mut c_void);
-
-        // Backend initialization/shutdown
-        pub fn imgui_init_for_glfw(window: *const GLFWwindow, install_callbacks: c_int) -> c_int;
-        pub fn imgui_init_for_opengl3(glsl_version: *const c_char) -> c_int;
-        pub fn imgui_shutdown_opengl3();
-        pub fn imgui_shutdown_glfw();
-
-        // Frame management
-        pub fn imgui_new_frame();
-        pub fn imgui_render();
-        pub fn imgui_end_frame();
-
-        // OpenGL3 backend rendering
-        pub fn imgui_opengl3_render_draw_data();
-
-        // IO access
-        pub fn imgui_io_set_display_size(width: c_float, height: c_float);
-        pub fn imgui_io_want_capture_mouse() -> c_int;
-        pub fn imgui_io_want_capture_keyboard() -> c_int;
-
-        // Basic widgets
-        pub fn imgui_begin(name: *const c_char, p_open: *mut c_int, flags: c_int) -> c_int;
-        pub fn imgui_end();
-        pub fn imgui_text(text: *const c_char);
-        pub fn imgui_button(label: *const c_char) -> c_int;
-        pub fn imgui_checkbox(label: *const c_char, v: *mut c_int) -> c_int;
-        pub fn imgui_slider_float(
-            label: *const c_char,
-            v: *mut c_float,
-            v_min: c_float,
-            v_max: c_float,
-        ) -> c_int;
-        pub fn imgui_slider_int(
-            label: *const c_char,
-            v: *mut c_int,
-            v_min: c_int,
-            v_max: c_int,
-        ) -> c_int;
-        pub fn imgui_input_float(label: *const c_char, v: *mut c_float) -> c_int;
-        pub fn imgui_input_int(label: *const c_char, v: *mut c_int) -> c_int;
-        pub fn imgui_color_edit3(label: *const c_char, col: *mut c_float) -> c_int;
-        pub fn imgui_color_edit4(label: *const c_char, col: *mut c_float) -> c_int;
-        pub fn imgui_same_line();
-        pub fn imgui_separator();
-        pub fn imgui_spacing();
-        pub fn imgui_dummy(width: c_float, height: c_float);
-        pub fn imgui_indent(indent_w: c_float);
-        pub fn imgui_unindent(indent_w: c_float);
-
-        // Tree nodes
-        pub fn imgui_tree_node(label: *const c_char) -> c_int;
-        pub fn imgui_tree_pop();
-
-        // Combo box
-        pub fn imgui_begin_combo(
-            label: *const c_char,
-            preview_value: *const c_char,
-            flags: c_int,
-        ) -> c_int;
-        pub fn imgui_end_combo();
-        pub fn imgui_selectable(label: *const c_char, selected: c_int, flags: c_int) -> c_int;
-
-        // Menu
-        pub fn imgui_begin_main_menu_bar() -> c_int;
-        pub fn imgui_end_main_menu_bar();
-        pub fn imgui_begin_menu(label: *const c_char, enabled: c_int) -> c_int;
-        pub fn imgui_end_menu();
-        pub fn imgui_menu_item(
-            label: *const c_char,
-            shortcut: *const c_char,
-            selected: c_int,
-            enabled: c_int,
-        ) -> c_int;
-
-        // Tooltips
-        pub fn imgui_set_tooltip(text: *const c_char);
-        pub fn imgui_begin_tooltip() -> c_int;
-        pub fn imgui_end_tooltip();
-
-        // Popups
-        pub fn imgui_begin_popup(str_id: *const c_char, flags: c_int) -> c_int;
-        pub fn imgui_begin_popup_modal(
-            name: *const c_char,
-            p_open: *mut c_int,
-            flags: c_int,
-        ) -> c_int;
-        pub fn imgui_end_popup();
-        pub fn imgui_open_popup(str_id: *const c_char);
-        pub fn imgui_close_current_popup();
-
-        // Tables
-        pub fn imgui_begin_table(str_id: *const c_char, column: c_int, flags: c_int) -> c_int;
-        pub fn imgui_end_table();
-        pub fn imgui_table_next_row();
-        pub fn imgui_table_next_column() -> c_int;
-        pub fn imgui_table_set_column_index(column_n: c_int) -> c_int;
-        pub fn imgui_table_setup_column(
-            label: *const c_char,
-            flags: c_int,
-            init_width_or_weight: c_float,
-        );
-        pub fn imgui_table_headers_row();
-
-        // Columns (legacy)
-        pub fn imgui_columns(count: c_int, id: *const c_char, border: c_int);
-        pub fn imgui_next_column();
-
-        // Style
-        pub fn imgui_push_style_color(idx: c_int, r: c_float, g: c_float, b: c_float, a: c_float);
-        pub fn imgui_pop_style_color(count: c_int);
-        pub fn imgui_push_style_var_float(idx: c_int, val: c_float);
-        pub fn imgui_push_style_var_vec2(idx: c_int, x: c_float, y: c_float);
-        pub fn imgui_pop_style_var(count: c_int);
-
-        // ID stack
-        pub fn imgui_push_id_int(int_id: c_int);
-        pub fn imgui_push_id_str(str_id: *const c_char);
-        pub fn imgui_pop_id();
-
-        // Utilities
-        pub fn imgui_is_item_hovered() -> c_int;
-        pub fn imgui_is_item_clicked(mouse_button: c_int) -> c_int;
-        pub fn imgui_is_item_active() -> c_int;
-        pub fn imgui_set_next_window_pos(x: c_float, y: c_float, cond: c_int);
-        pub fn imgui_set_next_window_size(width: c_float, height: c_float, cond: c_int);
-
-        // Demo window
-        pub fn imgui_show_demo_window(p_open: *mut c_int);
-
-        // DPI scaling
-        pub fn imgui_get_dpi_scale(window: *const GLFWwindow) -> c_float;
-        pub fn imgui_apply_dpi_scale(window: *const GLFWwindow);
-    }
-}
+use std::mem::MaybeUninit;
+
+mod color;
+mod data_type;
+mod draw_data;
+mod error;
+mod ffi;
+mod flags;
+mod font;
+mod format;
+mod input_text;
+mod key;
+mod render_target;
+mod rgba_image;
+mod scalar;
+mod software;
+mod string;
+mod style;
+mod text;
+mod texture;
+#[cfg(feature = "serde")]
+mod theme;
+mod tokens;
+mod ui;
+mod viewport;
+mod window;
+
+pub use color::{color_from_rgb, color_to_rgb, color_to_rgba, hsv_to_rgb, rgb_to_hsv};
+pub use data_type::DataType;
+pub use draw_data::{DrawCmd, DrawData, DrawList, DrawVert};
+pub use error::ImGuiError;
+pub use flags::{
+    ButtonFlags, ColorEditFlags, ComboFlags, Cond, InputTextFlags, SelectableFlags, SliderFlags, TableColumnFlags, TableFlags,
+    WindowFlags,
+};
+pub use font::{FontAtlas, FontConfig, FontId, GlyphRanges, GlyphRangesBuilder, DEFAULT_FONT_SIZE};
+pub use input_text::{HistoryDirection, InputTextCallbackData, InputTextEvent};
+pub use key::{Key, MouseButton};
+pub use render_target::RenderTarget;
+pub use rgba_image::RgbaImage;
+pub use scalar::{AngleSlider, InputScalar, Slider, VSlider};
+pub use software::SoftwareRenderer;
+pub use style::{Direction, Style, StyleColor, StyleVar};
+#[cfg(feature = "serde")]
+pub use theme::ThemeWatcher;
+
+pub use texture::TextureId;
+pub use tokens::{
+    ComboToken, FontToken, IdToken, MainMenuBarToken, MenuToken, PopupToken, StyleColorToken,
+    StyleVarToken, TableToken, TextWrapPosToken, TooltipToken, TreeNodeToken, WindowToken,
+};
+pub use ui::Ui;
+pub use viewport::Viewport;
+pub use window::WindowBuilder;
 
 /// Window flags for `begin()`
+#[deprecated(since = "0.2.0", note = "use `WindowFlags`")]
 pub mod window_flags {
     pub const NONE: i32 = 0;
     pub const NO_TITLE_BAR: i32 = 1 << 0;
@@ -205,6 +125,7 @@ pub mod window_flags {
 }
 
 /// Condition flags for `set_next_window_pos()` and `set_next_window_size()`
+#[deprecated(since = "0.2.0", note = "use `Cond`")]
 pub mod cond {
     pub const NONE: i32 = 0;
     pub const ALWAYS: i32 = 1 << 0;
@@ -214,6 +135,7 @@ pub mod cond {
 }
 
 /// Table flags for `begin_table()`
+#[deprecated(since = "0.2.0", note = "use `TableFlags`")]
 pub mod table_flags {
     pub const NONE: i32 = 0;
     pub const RESIZABLE: i32 = 1 << 0;
@@ -235,483 +157,440 @@ pub mod table_flags {
 }
 
 /// Style color indices for `push_style_color()`
+#[deprecated(since = "0.2.0", note = "use `StyleColor`")]
 pub mod col {
-    pub const TEXT: i32 = 0;
-    pub const TEXT_DISABLED: i32 = 1;
-    pub const WINDOW_BG: i32 = 2;
-    pub const CHILD_BG: i32 = 3;
-    pub const POPUP_BG: i32 = 4;
-    pub const BORDER: i32 = 5;
-    pub const BORDER_SHADOW: i32 = 6;
-    pub const FRAME_BG: i32 = 7;
-    pub const FRAME_BG_HOVERED: i32 = 8;
-    pub const FRAME_BG_ACTIVE: i32 = 9;
-    pub const TITLE_BG: i32 = 10;
-    pub const TITLE_BG_ACTIVE: i32 = 11;
-    pub const TITLE_BG_COLLAPSED: i32 = 12;
-    pub const MENU_BAR_BG: i32 = 13;
-    pub const SCROLLBAR_BG: i32 = 14;
-    pub const SCROLLBAR_GRAB: i32 = 15;
-    pub const SCROLLBAR_GRAB_HOVERED: i32 = 16;
-    pub const SCROLLBAR_GRAB_ACTIVE: i32 = 17;
-    pub const CHECK_MARK: i32 = 18;
-    pub const SLIDER_GRAB: i32 = 19;
-    pub const SLIDER_GRAB_ACTIVE: i32 = 20;
-    pub const BUTTON: i32 = 21;
-    pub const BUTTON_HOVERED: i32 = 22;
-    pub const BUTTON_ACTIVE: i32 = 23;
-    pub const HEADER: i32 = 24;
-    pub const HEADER_HOVERED: i32 = 25;
-    pub const HEADER_ACTIVE: i32 = 26;
+    use crate::StyleColor;
+
+    pub const TEXT: StyleColor = StyleColor::Text;
+    pub const TEXT_DISABLED: StyleColor = StyleColor::TextDisabled;
+    pub const WINDOW_BG: StyleColor = StyleColor::WindowBg;
+    pub const CHILD_BG: StyleColor = StyleColor::ChildBg;
+    pub const POPUP_BG: StyleColor = StyleColor::PopupBg;
+    pub const BORDER: StyleColor = StyleColor::Border;
+    pub const BORDER_SHADOW: StyleColor = StyleColor::BorderShadow;
+    pub const FRAME_BG: StyleColor = StyleColor::FrameBg;
+    pub const FRAME_BG_HOVERED: StyleColor = StyleColor::FrameBgHovered;
+    pub const FRAME_BG_ACTIVE: StyleColor = StyleColor::FrameBgActive;
+    pub const TITLE_BG: StyleColor = StyleColor::TitleBg;
+    pub const TITLE_BG_ACTIVE: StyleColor = StyleColor::TitleBgActive;
+    pub const TITLE_BG_COLLAPSED: StyleColor = StyleColor::TitleBgCollapsed;
+    pub const MENU_BAR_BG: StyleColor = StyleColor::MenuBarBg;
+    pub const SCROLLBAR_BG: StyleColor = StyleColor::ScrollbarBg;
+    pub const SCROLLBAR_GRAB: StyleColor = StyleColor::ScrollbarGrab;
+    pub const SCROLLBAR_GRAB_HOVERED: StyleColor = StyleColor::ScrollbarGrabHovered;
+    pub const SCROLLBAR_GRAB_ACTIVE: StyleColor = StyleColor::ScrollbarGrabActive;
+    pub const CHECK_MARK: StyleColor = StyleColor::CheckMark;
+    pub const SLIDER_GRAB: StyleColor = StyleColor::SliderGrab;
+    pub const SLIDER_GRAB_ACTIVE: StyleColor = StyleColor::SliderGrabActive;
+    pub const BUTTON: StyleColor = StyleColor::Button;
+    pub const BUTTON_HOVERED: StyleColor = StyleColor::ButtonHovered;
+    pub const BUTTON_ACTIVE: StyleColor = StyleColor::ButtonActive;
+    pub const HEADER: StyleColor = StyleColor::Header;
+    pub const HEADER_HOVERED: StyleColor = StyleColor::HeaderHovered;
+    pub const HEADER_ACTIVE: StyleColor = StyleColor::HeaderActive;
 }
 
 /// Dear ImGui context and safe wrapper
 pub struct ImGui {
     ctx: *mut std::ffi::c_void,
     window: *const GLFWwindow,
+    // Owns the font data ImGui's atlas points into
+    fonts: FontAtlas,
+    // Style in unscaled units; ImGui holds a copy scaled by `ui_scale`
+    base_style: Style,
+    ui_scale: f32,
 }
 
 impl ImGui {
     /// Create a new ImGui context and initialize backends for the given GLFW window.
     ///
-    /// On Windows, this automatically applies DPI scaling to handle high-DPI displays.
+    /// The style and fonts are scaled to the window's DPI, see `apply_dpi_scale()`.
     ///
     /// # Arguments
     /// * `window` - Raw GLFW window pointer from `Window::glfw_window_ptr()`
     /// * `install_callbacks` - If true, ImGui will install its own GLFW callbacks
     ///
+    /// # Errors
+    /// Returns an error if `window` is null or if either backend fails to
+    /// initialize. The partially created context is torn down before returning.
+    ///
     /// # Safety
     /// The window pointer must be valid for the lifetime of the ImGui context.
-    pub fn new(window: *const GLFWwindow, install_callbacks: bool) -> Self {
+    #[allow(clippy::not_unsafe_ptr_arg_deref)]
+    pub fn new(window: *const GLFWwindow, install_callbacks: bool) -> Result<Self, ImGuiError> {
+        if window.is_null() {
+            return Err(ImGuiError::NullWindow);
+        }
+
         let ctx = unsafe { ffi::imgui_create_context() };
 
-        let glsl_version = CString::new("#version 330").unwrap();
+        let glsl_version = CString::new("#version 330").unwrap_or_default();
         unsafe {
-            ffi::imgui_init_for_glfw(window, if install_callbacks { 1 } else { 0 });
-            ffi::imgui_init_for_opengl3(glsl_version.as_ptr());
-
-            // Apply DPI scaling on Windows
-            #[cfg(target_os = "windows")]
-            ffi::imgui_apply_dpi_scale(window);
+            if ffi::imgui_init_for_glfw(window, if install_callbacks { 1 } else { 0 }) == 0 {
+                ffi::imgui_destroy_context(ctx);
+                return Err(ImGuiError::GlfwInit);
+            }
+            if ffi::imgui_init_for_opengl3(glsl_version.as_ptr()) == 0 {
+                ffi::imgui_shutdown_glfw();
+                ffi::imgui_destroy_context(ctx);
+                return Err(ImGuiError::OpenGl3Init);
+            }
         }
 
-        Self { ctx, window }
+        let mut imgui = Self {
+            ctx,
+            window,
+            fonts: FontAtlas::new(),
+            base_style: Style::default(),
+            ui_scale: 1.0,
+        };
+        imgui.apply_dpi_scale();
+        Ok(imgui)
+    }
+
+    /// Create a context with no window or OpenGL backend, e.g. for tests.
+    ///
+    /// Frames are driven manually: set the time step with `set_delta_time()`
+    /// (1/60 s by default) and feed input with the `add_*_event()` methods.
+    /// `Ui::render()` only finalizes the frame; its geometry can be read with
+    /// `draw_data()` or rasterized with `Ui::render_software()`. The style and
+    /// fonts are not DPI scaled and no `imgui.ini` is written.
+    ///
+    /// ```ignore
+    /// let mut imgui = ImGui::new_headless([800.0, 600.0]);
+    /// imgui.add_mouse_pos_event([50.0, 40.0]);
+    /// let ui = imgui.new_frame();
+    /// let clicked = ui.button("OK");
+    /// ui.render();
+    /// assert!(imgui.draw_data().unwrap().total_vtx_count() > 0);
+    /// ```
+    ///
+    /// Dear ImGui keeps one current context per process. Each `ImGui` method
+    /// makes its own context current, so contexts can be used in turn, but
+    /// only one frame may be open at a time and not from several threads at
+    /// once: tests that create contexts must run serially
+    /// (`--test-threads=1` or a shared lock).
+    pub fn new_headless(display_size: [f32; 2]) -> Self {
+        let [width, height] = display_size;
+        let ctx = unsafe { ffi::imgui_create_headless_context(width.max(0.0), height.max(0.0)) };
+        let mut imgui = Self {
+            ctx,
+            window: std::ptr::null(),
+            fonts: FontAtlas::new(),
+            base_style: Style::default(),
+            ui_scale: 1.0,
+        };
+        let _ = imgui.apply_scale(1.0);
+        imgui
+    }
+
+    /// Returns true if the context was created with `new_headless()`.
+    pub fn is_headless(&self) -> bool {
+        self.window.is_null()
     }
 
     /// Get the DPI scale factor for the window.
     ///
     /// Returns the content scale reported by GLFW. On Windows with 150% display scaling,
-    /// this returns 1.5. On standard DPI displays, this returns 1.0.
+    /// this returns 1.5. On standard DPI displays, this returns 1.0. Headless
+    /// contexts always return 1.0.
     pub fn get_dpi_scale(&self) -> f32 {
+        if self.is_headless() {
+            return 1.0;
+        }
         unsafe { ffi::imgui_get_dpi_scale(self.window) }
     }
 
-    /// Manually apply DPI scaling.
+    /// The scale currently applied to the style and fonts.
     ///
-    /// This scales the ImGui style sizes and rebuilds the font atlas with a scaled font.
-    /// Called automatically on Windows during construction, but can be called manually
-    /// if DPI changes at runtime.
-    pub fn apply_dpi_scale(&self) {
-        unsafe { ffi::imgui_apply_dpi_scale(self.window) }
+    /// This is the GLFW content scale divided by the framebuffer scale, so it
+    /// stays 1.0 on displays where the framebuffer is already scaled (macOS
+    /// Retina, Wayland with fractional scaling) and follows the system scale
+    /// factor on Windows and X11.
+    pub fn ui_scale(&self) -> f32 {
+        self.ui_scale
     }
 
-    /// Start a new ImGui frame. Call this at the beginning of your render loop.
-    pub fn new_frame(&self) {
-        unsafe { ffi::imgui_new_frame() };
-    }
-
-    /// Finalize and render the ImGui frame. Call this at the end of your render loop.
-    pub fn render(&self) {
-        unsafe {
-            ffi::imgui_render();
-            ffi::imgui_opengl3_render_draw_data();
+    /// Apply the window's current DPI scale to the style and fonts.
+    ///
+    /// Sizes are always scaled from the unscaled base style and font sizes, so
+    /// calling this repeatedly does not compound the scale. `new_frame()`
+    /// calls it automatically when the content scale changes, e.g. when the
+    /// window moves to another monitor.
+    pub fn apply_dpi_scale(&mut self) {
+        let scale = self.current_ui_scale();
+        // A failure leaves the atlas empty, which ImGui fills with its default font
+        let _ = self.apply_scale(scale);
+    }
+
+    fn current_ui_scale(&self) -> f32 {
+        if self.is_headless() {
+            return self.ui_scale;
+        }
+        let framebuffer = unsafe { ffi::imgui_get_framebuffer_scale(self.window) };
+        if framebuffer <= 0.0 {
+            // Minimized: keep the current scale rather than rebuilding the
+            // fonts for a window nobody can see
+            return self.ui_scale;
+        }
+        let content = unsafe { ffi::imgui_get_dpi_scale(self.window) };
+        let scale = content / framebuffer;
+        if scale.is_finite() && scale > 0.0 {
+            scale
+        } else {
+            1.0
         }
     }
 
-    /// Returns true if ImGui wants to capture mouse input (e.g., mouse is over an ImGui window).
-    pub fn want_capture_mouse(&self) -> bool {
-        unsafe { ffi::imgui_io_want_capture_mouse() != 0 }
+    /// Make this the context ImGui's global functions act on, so several
+    /// contexts can be used one after the other.
+    fn make_current(&self) {
+        unsafe { ffi::imgui_set_current_context(self.ctx) };
     }
 
-    /// Returns true if ImGui wants to capture keyboard input.
-    pub fn want_capture_keyboard(&self) -> bool {
-        unsafe { ffi::imgui_io_want_capture_keyboard() != 0 }
+    fn apply_scale(&mut self, scale: f32) -> Result<(), ImGuiError> {
+        self.make_current();
+        // Keep edits made to the live style, e.g. in ImGui's style editor
+        self.base_style = self.style();
+        self.ui_scale = scale;
+        let style = scaled_style(&self.base_style, scale);
+        unsafe { ffi::imgui_set_style(&style) };
+        self.fonts.build(scale)
     }
 
-    // ---- Windows ----
-
-    /// Begin a new window. Returns true if the window is not collapsed.
+    /// Replace the loaded fonts and rebuild the font texture.
     ///
-    /// # Arguments
-    /// * `name` - Window title/ID
-    /// * `open` - Optional mutable bool; if Some, shows a close button
-    /// * `flags` - Window flags from `window_flags` module
-    pub fn begin(&self, name: &str, open: Option<&mut bool>, flags: i32) -> bool {
-        let name_c = CString::new(name).unwrap();
-        unsafe {
-            match open {
-                Some(open_ref) => {
-                    let mut open_int = if *open_ref { 1 } else { 0 };
-                    let result = ffi::imgui_begin(name_c.as_ptr(), &mut open_int, flags);
-                    *open_ref = open_int != 0;
-                    result != 0
-                }
-                None => ffi::imgui_begin(name_c.as_ptr(), ptr::null_mut(), flags) != 0,
-            }
+    /// Font sizes are in unscaled pixels and are multiplied by `ui_scale()`.
+    /// On failure the previous fonts are restored.
+    pub fn set_fonts(&mut self, fonts: FontAtlas) -> Result<(), ImGuiError> {
+        self.make_current();
+        if let Err(e) = fonts.build(self.ui_scale) {
+            // The previous atlas built before, so this only restores it
+            let _ = self.fonts.build(self.ui_scale);
+            return Err(e);
         }
+        self.fonts = fonts;
+        Ok(())
     }
 
-    /// End the current window. Must be called after `begin()`.
-    pub fn end(&self) {
-        unsafe { ffi::imgui_end() };
-    }
-
-    /// Set position of the next window.
-    pub fn set_next_window_pos(&self, x: f32, y: f32, cond: i32) {
-        unsafe { ffi::imgui_set_next_window_pos(x, y, cond) };
+    /// The fonts last applied with `set_fonts()`.
+    pub fn fonts(&self) -> &FontAtlas {
+        &self.fonts
     }
 
-    /// Set size of the next window.
-    pub fn set_next_window_size(&self, width: f32, height: f32, cond: i32) {
-        unsafe { ffi::imgui_set_next_window_size(width, height, cond) };
-    }
-
-    // ---- Widgets: Text ----
-
-    /// Display text.
-    pub fn text(&self, text: &str) {
-        let text_c = CString::new(text).unwrap();
-        unsafe { ffi::imgui_text(text_c.as_ptr()) };
-    }
-
-    // ---- Widgets: Buttons ----
-
-    /// Button widget. Returns true if clicked.
-    pub fn button(&self, label: &str) -> bool {
-        let label_c = CString::new(label).unwrap();
-        unsafe { ffi::imgui_button(label_c.as_ptr()) != 0 }
-    }
-
-    /// Checkbox widget. Returns true if value changed.
-    pub fn checkbox(&self, label: &str, v: &mut bool) -> bool {
-        let label_c = CString::new(label).unwrap();
-        let mut v_int = if *v { 1 } else { 0 };
-        let changed = unsafe { ffi::imgui_checkbox(label_c.as_ptr(), &mut v_int) != 0 };
-        *v = v_int != 0;
-        changed
-    }
-
-    // ---- Widgets: Sliders ----
-
-    /// Float slider. Returns true if value changed.
-    pub fn slider_float(&self, label: &str, v: &mut f32, min: f32, max: f32) -> bool {
-        let label_c = CString::new(label).unwrap();
-        unsafe { ffi::imgui_slider_float(label_c.as_ptr(), v, min, max) != 0 }
-    }
-
-    /// Int slider. Returns true if value changed.
-    pub fn slider_int(&self, label: &str, v: &mut i32, min: i32, max: i32) -> bool {
-        let label_c = CString::new(label).unwrap();
-        unsafe { ffi::imgui_slider_int(label_c.as_ptr(), v, min, max) != 0 }
-    }
-
-    // ---- Widgets: Input ----
-
-    /// Float input. Returns true if value changed.
-    pub fn input_float(&self, label: &str, v: &mut f32) -> bool {
-        let label_c = CString::new(label).unwrap();
-        unsafe { ffi::imgui_input_float(label_c.as_ptr(), v) != 0 }
-    }
-
-    /// Int input. Returns true if value changed.
-    pub fn input_int(&self, label: &str, v: &mut i32) -> bool {
-        let label_c = CString::new(label).unwrap();
-        unsafe { ffi::imgui_input_int(label_c.as_ptr(), v) != 0 }
-    }
-
-    // ---- Widgets: Color ----
-
-    /// RGB color editor. Returns true if value changed.
-    pub fn color_edit3(&self, label: &str, col: &mut [f32; 3]) -> bool {
-        let label_c = CString::new(label).unwrap();
-        unsafe { ffi::imgui_color_edit3(label_c.as_ptr(), col.as_mut_ptr()) != 0 }
-    }
-
-    /// RGBA color editor. Returns true if value changed.
-    pub fn color_edit4(&self, label: &str, col: &mut [f32; 4]) -> bool {
-        let label_c = CString::new(label).unwrap();
-        unsafe { ffi::imgui_color_edit4(label_c.as_ptr(), col.as_mut_ptr()) != 0 }
-    }
-
-    // ---- Layout ----
-
-    /// Place next widget on the same line as the previous one.
-    pub fn same_line(&self) {
-        unsafe { ffi::imgui_same_line() };
-    }
-
-    /// Add a horizontal separator.
-    pub fn separator(&self) {
-        unsafe { ffi::imgui_separator() };
-    }
-
-    /// Add vertical spacing.
-    pub fn spacing(&self) {
-        unsafe { ffi::imgui_spacing() };
-    }
-
-    /// Add a dummy item of given size.
-    pub fn dummy(&self, width: f32, height: f32) {
-        unsafe { ffi::imgui_dummy(width, height) };
-    }
-
-    /// Indent content.
-    pub fn indent(&self, indent_w: f32) {
-        unsafe { ffi::imgui_indent(indent_w) };
-    }
-
-    /// Unindent content.
-    pub fn unindent(&self, indent_w: f32) {
-        unsafe { ffi::imgui_unindent(indent_w) };
-    }
-
-    // ---- Tree ----
-
-    /// Begin a tree node. Returns true if the node is open.
-    pub fn tree_node(&self, label: &str) -> bool {
-        let label_c = CString::new(label).unwrap();
-        unsafe { ffi::imgui_tree_node(label_c.as_ptr()) != 0 }
-    }
-
-    /// End a tree node. Call only if `tree_node()` returned true.
-    pub fn tree_pop(&self) {
-        unsafe { ffi::imgui_tree_pop() };
-    }
-
-    // ---- Combo ----
-
-    /// Begin a combo box. Returns true if the combo is open.
-    pub fn begin_combo(&self, label: &str, preview: &str, flags: i32) -> bool {
-        let label_c = CString::new(label).unwrap();
-        let preview_c = CString::new(preview).unwrap();
-        unsafe { ffi::imgui_begin_combo(label_c.as_ptr(), preview_c.as_ptr(), flags) != 0 }
-    }
-
-    /// End a combo box. Call only if `begin_combo()` returned true.
-    pub fn end_combo(&self) {
-        unsafe { ffi::imgui_end_combo() };
-    }
-
-    /// Selectable item in a combo/list. Returns true if clicked.
-    pub fn selectable(&self, label: &str, selected: bool, flags: i32) -> bool {
-        let label_c = CString::new(label).unwrap();
-        unsafe { ffi::imgui_selectable(label_c.as_ptr(), if selected { 1 } else { 0 }, flags) != 0 }
-    }
-
-    // ---- Menu ----
-
-    /// Begin the main menu bar.
-    pub fn begin_main_menu_bar(&self) -> bool {
-        unsafe { ffi::imgui_begin_main_menu_bar() != 0 }
-    }
-
-    /// End the main menu bar.
-    pub fn end_main_menu_bar(&self) {
-        unsafe { ffi::imgui_end_main_menu_bar() };
-    }
-
-    /// Begin a menu.
-    pub fn begin_menu(&self, label: &str, enabled: bool) -> bool {
-        let label_c = CString::new(label).unwrap();
-        unsafe { ffi::imgui_begin_menu(label_c.as_ptr(), if enabled { 1 } else { 0 }) != 0 }
-    }
-
-    /// End a menu.
-    pub fn end_menu(&self) {
-        unsafe { ffi::imgui_end_menu() };
-    }
-
-    /// Menu item. Returns true if activated.
-    pub fn menu_item(&self, label: &str, shortcut: Option<&str>, selected: bool, enabled: bool) -> bool {
-        let label_c = CString::new(label).unwrap();
-        let shortcut_c = shortcut.map(|s| CString::new(s).unwrap());
-        let shortcut_ptr = shortcut_c.as_ref().map_or(ptr::null(), |s| s.as_ptr());
-        unsafe {
-            ffi::imgui_menu_item(
-                label_c.as_ptr(),
-                shortcut_ptr,
-                if selected { 1 } else { 0 },
-                if enabled { 1 } else { 0 },
-            ) != 0
+    /// Get a copy of the current style, in unscaled units.
+    ///
+    /// This is ImGui's live style, so it includes changes made in the style
+    /// editor or with `push_style_var()` inside a frame, divided back by
+    /// `ui_scale()`.
+    pub fn style(&self) -> Style {
+        self.make_current();
+        let mut live = MaybeUninit::<Style>::uninit();
+        let mut live = unsafe {
+            ffi::imgui_get_style(live.as_mut_ptr());
+            live.assume_init()
+        };
+        if live == scaled_style(&self.base_style, self.ui_scale) {
+            // Unchanged since it was applied; avoid the rounding of scaling back
+            return self.base_style;
         }
-    }
-
-    // ---- Tooltips ----
-
-    /// Set a tooltip for the previous item.
-    pub fn set_tooltip(&self, text: &str) {
-        let text_c = CString::new(text).unwrap();
-        unsafe { ffi::imgui_set_tooltip(text_c.as_ptr()) };
-    }
-
-    /// Begin a tooltip.
-    pub fn begin_tooltip(&self) -> bool {
-        unsafe { ffi::imgui_begin_tooltip() != 0 }
-    }
-
-    /// End a tooltip.
-    pub fn end_tooltip(&self) {
-        unsafe { ffi::imgui_end_tooltip() };
-    }
-
-    // ---- Popups ----
-
-    /// Begin a popup.
-    pub fn begin_popup(&self, str_id: &str, flags: i32) -> bool {
-        let str_id_c = CString::new(str_id).unwrap();
-        unsafe { ffi::imgui_begin_popup(str_id_c.as_ptr(), flags) != 0 }
-    }
-
-    /// Begin a modal popup.
-    pub fn begin_popup_modal(&self, name: &str, open: Option<&mut bool>, flags: i32) -> bool {
-        let name_c = CString::new(name).unwrap();
-        unsafe {
-            match open {
-                Some(open_ref) => {
-                    let mut open_int = if *open_ref { 1 } else { 0 };
-                    let result = ffi::imgui_begin_popup_modal(name_c.as_ptr(), &mut open_int, flags);
-                    *open_ref = open_int != 0;
-                    result != 0
-                }
-                None => ffi::imgui_begin_popup_modal(name_c.as_ptr(), ptr::null_mut(), flags) != 0,
-            }
+        if self.ui_scale != 1.0 {
+            live.scale_all_sizes(1.0 / self.ui_scale);
         }
+        live
     }
 
-    /// End a popup.
-    pub fn end_popup(&self) {
-        unsafe { ffi::imgui_end_popup() };
-    }
-
-    /// Open a popup.
-    pub fn open_popup(&self, str_id: &str) {
-        let str_id_c = CString::new(str_id).unwrap();
-        unsafe { ffi::imgui_open_popup(str_id_c.as_ptr()) };
-    }
-
-    /// Close the current popup.
-    pub fn close_current_popup(&self) {
-        unsafe { ffi::imgui_close_current_popup() };
-    }
-
-    // ---- Tables ----
-
-    /// Begin a table.
-    pub fn begin_table(&self, str_id: &str, columns: i32, flags: i32) -> bool {
-        let str_id_c = CString::new(str_id).unwrap();
-        unsafe { ffi::imgui_begin_table(str_id_c.as_ptr(), columns, flags) != 0 }
+    /// Replace the current style.
+    ///
+    /// Sizes are in unscaled units; ImGui receives a copy multiplied by
+    /// `ui_scale()`. To switch only the colors:
+    ///
+    /// ```ignore
+    /// let mut style = imgui.style();
+    /// style.use_light_colors();
+    /// imgui.set_style(&style)?;
+    /// ```
+    ///
+    /// # Errors
+    /// Returns `ImGuiError::InvalidStyle`, leaving the current style in
+    /// place, if `style` fails `Style::validate()`.
+    pub fn set_style(&mut self, style: &Style) -> Result<(), ImGuiError> {
+        style.validate()?;
+        self.make_current();
+        self.base_style = *style;
+        let scaled = scaled_style(style, self.ui_scale);
+        unsafe { ffi::imgui_set_style(&scaled) };
+        Ok(())
     }
 
-    /// End a table.
-    pub fn end_table(&self) {
-        unsafe { ffi::imgui_end_table() };
+    /// Start a new ImGui frame. Call this at the beginning of your render loop.
+    ///
+    /// All widgets are submitted through the returned `Ui`, which borrows the
+    /// context until the frame is finished with `Ui::render()`.
+    ///
+    /// # Panics
+    /// If the `Ui` of another context's frame is still alive: widgets go to
+    /// the current context, so two open frames would mix their contents.
+    pub fn new_frame(&mut self) -> Ui<'_> {
+        ui::open_frame(self.ctx);
+        self.make_current();
+        let scale = self.current_ui_scale();
+        if scale != self.ui_scale {
+            let _ = self.apply_scale(scale);
+        }
+        unsafe { ffi::imgui_new_frame() };
+        Ui::new(self)
     }
 
-    /// Move to the next row in a table.
-    pub fn table_next_row(&self) {
-        unsafe { ffi::imgui_table_next_row() };
+    /// Geometry of the last frame finished with `Ui::render()` or
+    /// `Ui::render_software()`, or `None` before the first one.
+    pub fn draw_data(&self) -> Option<DrawData<'_>> {
+        self.make_current();
+        // Safety: the buffers live until the next frame, which needs `&mut self`
+        unsafe { draw_data::draw_data() }
     }
 
-    /// Move to the next column in a table.
-    pub fn table_next_column(&self) -> bool {
-        unsafe { ffi::imgui_table_next_column() != 0 }
+    /// Draw the last frame finished with `Ui::render()` into `target`.
+    ///
+    /// The UI is stretched to cover the whole target, so a target with the
+    /// size of `display_framebuffer_size()` reproduces the window exactly.
+    /// The target is not cleared first.
+    ///
+    /// ```ignore
+    /// ui.render();
+    /// if take_screenshot {
+    ///     let [width, height] = imgui.display_framebuffer_size();
+    ///     let target = RenderTarget::new(width, height)?;
+    ///     target.clear([0.1, 0.1, 0.1, 1.0]);
+    ///     imgui.render_to_target(&target)?;
+    ///     let shot = target.capture_rgba();
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    /// Returns `ImGuiError::NoDrawData` if no frame has been rendered yet, or
+    /// `ImGuiError::Headless` for a headless context.
+    pub fn render_to_target(&self, target: &RenderTarget) -> Result<(), ImGuiError> {
+        self.make_current();
+        if self.is_headless() {
+            return Err(ImGuiError::Headless);
+        }
+        let [width, height] = target.size();
+        if unsafe { ffi::imgui_opengl3_render_draw_data_to(target.framebuffer(), width as i32, height as i32) } == 0 {
+            return Err(ImGuiError::NoDrawData);
+        }
+        Ok(())
     }
 
-    /// Set the current column index.
-    pub fn table_set_column_index(&self, column: i32) -> bool {
-        unsafe { ffi::imgui_table_set_column_index(column) != 0 }
+    /// Pixel size of the last rendered frame, or `[0, 0]` if there is none.
+    pub fn display_framebuffer_size(&self) -> [u32; 2] {
+        self.make_current();
+        let mut size = [0; 2];
+        if unsafe { ffi::imgui_draw_data_framebuffer_size(size.as_mut_ptr()) } == 0 {
+            return [0, 0];
+        }
+        size.map(|s| s.max(0) as u32)
     }
 
-    /// Setup a column (call before first row).
-    pub fn table_setup_column(&self, label: &str, flags: i32, init_width: f32) {
-        let label_c = CString::new(label).unwrap();
-        unsafe { ffi::imgui_table_setup_column(label_c.as_ptr(), flags, init_width) };
+    /// Capture the last frame finished with `Ui::render()` at the window's
+    /// pixel size, on a transparent background.
+    ///
+    /// # Errors
+    /// Returns `ImGuiError::NoDrawData` if no frame has been rendered yet,
+    /// `ImGuiError::Headless` for a headless context, or
+    /// `ImGuiError::RenderTarget` if the offscreen target cannot be created.
+    /// Headless contexts can use `Ui::render_software()` instead.
+    pub fn capture_rgba(&self) -> Result<RgbaImage, ImGuiError> {
+        if self.is_headless() {
+            return Err(ImGuiError::Headless);
+        }
+        let [width, height] = self.display_framebuffer_size();
+        if width == 0 || height == 0 {
+            return Err(ImGuiError::NoDrawData);
+        }
+        let target = RenderTarget::new(width, height)?;
+        target.clear([0.0; 4]);
+        self.render_to_target(&target)?;
+        Ok(target.capture_rgba())
     }
 
-    /// Display column headers row.
-    pub fn table_headers_row(&self) {
-        unsafe { ffi::imgui_table_headers_row() };
+    /// Set the display size for the next frame. The GLFW backend does this
+    /// every frame from the window size, so this is meant for headless contexts.
+    pub fn set_display_size(&mut self, display_size: [f32; 2]) {
+        self.make_current();
+        unsafe { ffi::imgui_io_set_display_size(display_size[0].max(0.0), display_size[1].max(0.0)) };
     }
 
-    // ---- Style ----
-
-    /// Push a style color.
-    pub fn push_style_color(&self, idx: i32, r: f32, g: f32, b: f32, a: f32) {
-        unsafe { ffi::imgui_push_style_color(idx, r, g, b, a) };
+    /// Set the time in seconds since the last frame, for headless contexts.
+    ///
+    /// # Panics
+    /// Panics if `delta_time` is not positive; ImGui requires time to advance.
+    pub fn set_delta_time(&mut self, delta_time: f32) {
+        self.make_current();
+        assert!(delta_time > 0.0, "delta time must be positive, got {}", delta_time);
+        unsafe { ffi::imgui_io_set_delta_time(delta_time) };
     }
 
-    /// Pop style colors.
-    pub fn pop_style_color(&self, count: i32) {
-        unsafe { ffi::imgui_pop_style_color(count) };
+    /// Queue a mouse move, in display coordinates.
+    pub fn add_mouse_pos_event(&mut self, pos: [f32; 2]) {
+        self.make_current();
+        unsafe { ffi::imgui_io_add_mouse_pos_event(pos[0], pos[1]) };
     }
 
-    // ---- ID Stack ----
-
-    /// Push an integer ID.
-    pub fn push_id_int(&self, id: i32) {
-        unsafe { ffi::imgui_push_id_int(id) };
+    /// Queue a mouse button press or release.
+    pub fn add_mouse_button_event(&mut self, button: MouseButton, down: bool) {
+        self.make_current();
+        unsafe { ffi::imgui_io_add_mouse_button_event(button as i32, down as i32) };
     }
 
-    /// Push a string ID.
-    pub fn push_id(&self, id: &str) {
-        let id_c = CString::new(id).unwrap();
-        unsafe { ffi::imgui_push_id_str(id_c.as_ptr()) };
+    /// Queue a mouse wheel scroll; positive `y` scrolls up.
+    pub fn add_mouse_wheel_event(&mut self, wheel: [f32; 2]) {
+        self.make_current();
+        unsafe { ffi::imgui_io_add_mouse_wheel_event(wheel[0], wheel[1]) };
     }
 
-    /// Pop an ID.
-    pub fn pop_id(&self) {
-        unsafe { ffi::imgui_pop_id() };
+    /// Queue a key press or release.
+    pub fn add_key_event(&mut self, key: Key, down: bool) {
+        self.make_current();
+        unsafe { ffi::imgui_io_add_key_event(key as i32, down as i32) };
     }
 
-    // ---- Item Queries ----
-
-    /// Returns true if the last item is hovered.
-    pub fn is_item_hovered(&self) -> bool {
-        unsafe { ffi::imgui_is_item_hovered() != 0 }
+    /// Queue a typed character for the active text input.
+    pub fn add_input_character(&mut self, c: char) {
+        self.make_current();
+        unsafe { ffi::imgui_io_add_input_character(c as u32) };
     }
 
-    /// Returns true if the last item was clicked.
-    pub fn is_item_clicked(&self, mouse_button: i32) -> bool {
-        unsafe { ffi::imgui_is_item_clicked(mouse_button) != 0 }
+    /// Returns true if ImGui wants to capture mouse input (e.g., mouse is over an ImGui window).
+    pub fn want_capture_mouse(&self) -> bool {
+        self.make_current();
+        unsafe { ffi::imgui_io_want_capture_mouse() != 0 }
     }
 
-    /// Returns true if the last item is active.
-    pub fn is_item_active(&self) -> bool {
-        unsafe { ffi::imgui_is_item_active() != 0 }
+    /// Returns true if ImGui wants to capture keyboard input.
+    pub fn want_capture_keyboard(&self) -> bool {
+        self.make_current();
+        unsafe { ffi::imgui_io_want_capture_keyboard() != 0 }
     }
+}
 
-    // ---- Demo ----
-
-    /// Show the ImGui demo window.
-    pub fn show_demo_window(&self, open: Option<&mut bool>) {
-        unsafe {
-            match open {
-                Some(open_ref) => {
-                    let mut open_int = if *open_ref { 1 } else { 0 };
-                    ffi::imgui_show_demo_window(&mut open_int);
-                    *open_ref = open_int != 0;
-                }
-                None => ffi::imgui_show_demo_window(ptr::null_mut()),
-            }
-        }
-    }
+/// `style` with its sizes multiplied by `scale`. `ScaleAllSizes` truncates,
+/// so the minimum window size is kept at the 1 pixel ImGui asserts on.
+fn scaled_style(style: &Style, scale: f32) -> Style {
+    let mut scaled = *style;
+    scaled.scale_all_sizes(scale);
+    scaled.window_min_size = scaled.window_min_size.map(|v| v.max(1.0));
+    scaled
 }
 
 impl Drop for ImGui {
     fn drop(&mut self) {
+        self.make_current();
         unsafe {
-            ffi::imgui_shutdown_opengl3();
-            ffi::imgui_shutdown_glfw();
+            if !self.is_headless() {
+                ffi::imgui_shutdown_opengl3();
+                ffi::imgui_shutdown_glfw();
+            }
             ffi::imgui_destroy_context(self.ctx);
         }
     }
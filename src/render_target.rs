@@ -0,0 +1,124 @@
+//! Offscreen framebuffers that can be drawn into and shown with `image()`.
+
+use crate::{ffi, ImGuiError, RgbaImage, TextureId};
+
+/// An OpenGL framebuffer with an RGBA8 color texture.
+///
+/// Anything drawn inside `draw()` lands in the texture instead of the
+/// window, and the texture can then be shown with `Ui::image()`. OpenGL
+/// stores rows bottom-up, so pass `uv0 = [0.0, 1.0]` and `uv1 = [1.0, 0.0]`
+/// to show it upright.
+///
+/// The GL objects are deleted on drop, which needs the context the target
+/// was created in to be current.
+#[derive(Debug)]
+pub struct RenderTarget {
+    fbo: u32,
+    texture: u32,
+    size: [u32; 2],
+}
+
+impl RenderTarget {
+    /// Create a target of `width` x `height` pixels.
+    ///
+    /// # Errors
+    /// Returns `ImGuiError::RenderTarget` if a size is zero, no OpenGL 3
+    /// context is current or the framebuffer is incomplete.
+    pub fn new(width: u32, height: u32) -> Result<Self, ImGuiError> {
+        let error = ImGuiError::RenderTarget { width, height };
+        let (w, h) = match (i32::try_from(width), i32::try_from(height)) {
+            (Ok(w), Ok(h)) => (w, h),
+            _ => return Err(error),
+        };
+        let mut texture = 0;
+        let fbo = unsafe { ffi::imgui_render_target_create(w, h, &mut texture) };
+        if fbo == 0 {
+            return Err(error);
+        }
+        Ok(Self {
+            fbo,
+            texture,
+            size: [width, height],
+        })
+    }
+
+    /// Size in pixels.
+    pub fn size(&self) -> [u32; 2] {
+        self.size
+    }
+
+    /// Reallocate the texture at a new size, discarding its contents.
+    /// Sizes are clamped to at least one pixel.
+    pub fn resize(&mut self, width: u32, height: u32) {
+        let size = [width.clamp(1, i32::MAX as u32), height.clamp(1, i32::MAX as u32)];
+        if size != self.size {
+            unsafe { ffi::imgui_render_target_resize(self.texture, size[0] as i32, size[1] as i32) };
+            self.size = size;
+        }
+    }
+
+    /// The color texture, for `Ui::image()`.
+    pub fn texture(&self) -> TextureId {
+        TextureId::from_gl(self.texture)
+    }
+
+    /// The OpenGL framebuffer name.
+    pub fn framebuffer(&self) -> u32 {
+        self.fbo
+    }
+
+    /// Fill the whole target with `color`.
+    pub fn clear(&self, color: [f32; 4]) {
+        self.draw(|| unsafe { ffi::imgui_render_target_clear(color[0], color[1], color[2], color[3]) });
+    }
+
+    /// Read the target's pixels back from the GPU.
+    ///
+    /// This waits for all pending drawing to finish, so it is meant for
+    /// screenshots rather than every frame.
+    pub fn capture_rgba(&self) -> RgbaImage {
+        let [width, height] = self.size;
+        let mut pixels = vec![0; width as usize * height as usize * 4];
+        unsafe { ffi::imgui_render_target_read_pixels(self.fbo, width as i32, height as i32, pixels.as_mut_ptr()) };
+        RgbaImage::from_gl_rows(width, height, pixels)
+    }
+
+    /// Run `f` with the target bound and the GL viewport covering it.
+    ///
+    /// The previous framebuffer and viewport are restored afterwards, also
+    /// if `f` panics.
+    pub fn draw<R, F: FnOnce() -> R>(&self, f: F) -> R {
+        self.draw_with_viewport([0, 0, self.size[0] as i32, self.size[1] as i32], f)
+    }
+
+    /// Like `draw()`, with the GL viewport set to `[x, y, width, height]`.
+    pub(crate) fn draw_with_viewport<R, F: FnOnce() -> R>(&self, viewport: [i32; 4], f: F) -> R {
+        let _binding = Binding::new(self.fbo, viewport);
+        f()
+    }
+}
+
+impl Drop for RenderTarget {
+    fn drop(&mut self) {
+        unsafe { ffi::imgui_render_target_destroy(self.fbo, self.texture) };
+    }
+}
+
+/// Restores the GL state saved by `imgui_render_target_bind` when dropped.
+struct Binding {
+    saved: [i32; 9],
+}
+
+impl Binding {
+    fn new(fbo: u32, [x, y, width, height]: [i32; 4]) -> Self {
+        let mut saved = [0; 9];
+        unsafe { ffi::imgui_render_target_bind(fbo, x, y, width, height, saved.as_mut_ptr()) };
+        Self { saved }
+    }
+}
+
+impl Drop for Binding {
+    fn drop(&mut self) {
+        unsafe { ffi::imgui_render_target_unbind(self.saved.as_ptr()) };
+    }
+}
@@ -0,0 +1,130 @@
+//! CPU-side RGBA pixel buffers, e.g. screenshots of the UI.
+
+/// An 8-bit RGBA image with rows stored top to bottom.
+///
+/// The pixel layout matches `image::RgbaImage`, so the raw bytes convert
+/// without copying:
+///
+/// ```ignore
+/// let shot = imgui.capture_rgba()?;
+/// let (width, height) = (shot.width(), shot.height());
+/// image::RgbaImage::from_raw(width, height, shot.into_raw()).unwrap().save("ui.png")?;
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RgbaImage {
+    width: u32,
+    height: u32,
+    pixels: Vec<u8>,
+}
+
+impl RgbaImage {
+    /// A transparent black image.
+    pub fn new(width: u32, height: u32) -> Self {
+        Self {
+            width,
+            height,
+            pixels: vec![0; width as usize * height as usize * 4],
+        }
+    }
+
+    /// Wrap raw RGBA bytes, or `None` if `pixels` is not `width * height * 4`
+    /// bytes long.
+    pub fn from_raw(width: u32, height: u32, pixels: Vec<u8>) -> Option<Self> {
+        if pixels.len() == width as usize * height as usize * 4 {
+            Some(Self { width, height, pixels })
+        } else {
+            None
+        }
+    }
+
+    /// Width in pixels.
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    /// Height in pixels.
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    /// The RGBA bytes, row by row from the top.
+    pub fn as_raw(&self) -> &[u8] {
+        &self.pixels
+    }
+
+    /// Mutable access to the RGBA bytes.
+    pub fn as_raw_mut(&mut self) -> &mut [u8] {
+        &mut self.pixels
+    }
+
+    /// Take the RGBA bytes.
+    pub fn into_raw(self) -> Vec<u8> {
+        self.pixels
+    }
+
+    /// The pixel at column `x` of row `y`.
+    ///
+    /// # Panics
+    /// Panics if the position is outside the image.
+    pub fn get_pixel(&self, x: u32, y: u32) -> [u8; 4] {
+        let i = self.index(x, y);
+        [self.pixels[i], self.pixels[i + 1], self.pixels[i + 2], self.pixels[i + 3]]
+    }
+
+    /// Set the pixel at column `x` of row `y`.
+    ///
+    /// # Panics
+    /// Panics if the position is outside the image.
+    pub fn put_pixel(&mut self, x: u32, y: u32, pixel: [u8; 4]) {
+        let i = self.index(x, y);
+        self.pixels[i..i + 4].copy_from_slice(&pixel);
+    }
+
+    fn index(&self, x: u32, y: u32) -> usize {
+        assert!(
+            x < self.width && y < self.height,
+            "pixel ({}, {}) is outside a {}x{} image",
+            x,
+            y,
+            self.width,
+            self.height
+        );
+        (y as usize * self.width as usize + x as usize) * 4
+    }
+
+    /// Build an image from bottom-up rows, as OpenGL returns them.
+    pub(crate) fn from_gl_rows(width: u32, height: u32, mut pixels: Vec<u8>) -> Self {
+        let stride = width as usize * 4;
+        let rows = height as usize;
+        for row in 0..rows / 2 {
+            let (top, bottom) = pixels.split_at_mut((rows - 1 - row) * stride);
+            top[row * stride..(row + 1) * stride].swap_with_slice(&mut bottom[..stride]);
+        }
+        Self { width, height, pixels }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn gl_rows_are_flipped_top_down() {
+        // A 2x3 image whose rows are bottom-up, each pixel tagged with its row
+        let pixels = [2u8, 1, 0].iter().flat_map(|&row| [row, row, row, 255].repeat(2)).collect();
+        let image = RgbaImage::from_gl_rows(2, 3, pixels);
+        assert_eq!((image.width(), image.height()), (2, 3));
+        for y in 0..3 {
+            for x in 0..2 {
+                assert_eq!(image.get_pixel(x, y), [y as u8, y as u8, y as u8, 255], "pixel {}, {}", x, y);
+            }
+        }
+    }
+
+    #[test]
+    fn gl_rows_keep_pixel_order_within_a_row() {
+        let pixels = (0..2 * 2 * 4).collect();
+        let image = RgbaImage::from_gl_rows(2, 2, pixels);
+        assert_eq!(image.as_raw(), [8, 9, 10, 11, 12, 13, 14, 15, 0, 1, 2, 3, 4, 5, 6, 7]);
+    }
+}
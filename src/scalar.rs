@@ -0,0 +1,300 @@
+//! Builders for numeric widgets with a format, flags or step.
+
+use std::ops::RangeInclusive;
+use std::{ptr, slice};
+
+use crate::data_type::{check_slider_range, data_type_id, slider_range, FLOAT};
+use crate::format::{check_format, valid_format};
+use crate::string::{check_nul, str_range};
+use crate::{ffi, DataType, ImGuiError, InputTextFlags, SliderFlags, Ui};
+
+/// Fail on `SliderFlags::WRAP_AROUND`, which ImGui only supports on drags.
+fn check_slider_flags(flags: SliderFlags) -> Result<(), ImGuiError> {
+    if flags.contains(SliderFlags::WRAP_AROUND) {
+        return Err(ImGuiError::InvalidFlags {
+            message: "WRAP_AROUND only applies to drag widgets".to_string(),
+        });
+    }
+    Ok(())
+}
+
+/// Slider for any numeric type.
+///
+/// ```ignore
+/// Slider::new("X", 0.0..=800.0)
+///     .format("%.0f px")
+///     .flags(SliderFlags::ALWAYS_CLAMP)
+///     .build(&ui, &mut x);
+/// ```
+#[must_use = "call `build()` to submit the slider"]
+pub struct Slider<'a, T: DataType> {
+    label: &'a str,
+    range: RangeInclusive<T>,
+    format: Option<&'a str>,
+    flags: SliderFlags,
+}
+
+impl<'a, T: DataType> Slider<'a, T> {
+    /// A slider editing values within `range`, which `build()` clamps to
+    /// half of the type's limits for 32-bit and wider types.
+    pub fn new(label: &'a str, range: RangeInclusive<T>) -> Self {
+        Self {
+            label,
+            range,
+            format: None,
+            flags: SliderFlags::empty(),
+        }
+    }
+
+    /// Printf format of the value, e.g. `"%.1f"` or `"%d items"`.
+    pub fn format(mut self, format: &'a str) -> Self {
+        self.format = Some(format);
+        self
+    }
+
+    /// Slider flags.
+    pub fn flags(mut self, flags: SliderFlags) -> Self {
+        self.flags = flags;
+        self
+    }
+
+    /// Submit the slider. Returns true if the value changed.
+    pub fn build(self, ui: &Ui, v: &mut T) -> bool {
+        self.build_array(ui, slice::from_mut(v))
+    }
+
+    /// Submit one slider per element of `v`. Returns true if any value changed.
+    pub fn build_array(self, ui: &Ui, v: &mut [T]) -> bool {
+        ui.slider_scalar_n(self.label, v, self.range, self.format, self.flags)
+    }
+
+    /// Like `build()`, but fails on a NUL byte in the label, an invalid
+    /// format, a range `build()` would clamp or `SliderFlags::WRAP_AROUND`.
+    pub fn try_build(self, ui: &Ui, v: &mut T) -> Result<bool, ImGuiError> {
+        self.try_build_array(ui, slice::from_mut(v))
+    }
+
+    /// Like `build_array()`, but fails on a NUL byte in the label, an
+    /// invalid format, a range `build_array()` would clamp or
+    /// `SliderFlags::WRAP_AROUND`.
+    pub fn try_build_array(self, ui: &Ui, v: &mut [T]) -> Result<bool, ImGuiError> {
+        check_nul(self.label)?;
+        if let Some(format) = self.format {
+            check_format(format, data_type_id::<T>())?;
+        }
+        check_slider_range(&self.range)?;
+        check_slider_flags(self.flags)?;
+        Ok(self.build_array(ui, v))
+    }
+}
+
+/// Text input for any numeric type, with optional +/- step buttons.
+///
+/// ```ignore
+/// InputScalar::new("Radius")
+///     .step(1.0, 10.0)
+///     .format("%.2f")
+///     .build(&ui, &mut radius);
+/// ```
+#[must_use = "call `build()` to submit the input"]
+pub struct InputScalar<'a, T: DataType> {
+    label: &'a str,
+    step: Option<T>,
+    step_fast: Option<T>,
+    format: Option<&'a str>,
+    flags: InputTextFlags,
+}
+
+impl<'a, T: DataType> InputScalar<'a, T> {
+    /// An input without step buttons.
+    pub fn new(label: &'a str) -> Self {
+        Self {
+            label,
+            step: None,
+            step_fast: None,
+            format: None,
+            flags: InputTextFlags::empty(),
+        }
+    }
+
+    /// Show +/- buttons adding `step`, or `step_fast` while Ctrl is held.
+    pub fn step(mut self, step: T, step_fast: T) -> Self {
+        self.step = Some(step);
+        self.step_fast = Some(step_fast);
+        self
+    }
+
+    /// Printf format of the value, e.g. `"%.3f"` or `"%08X"`.
+    pub fn format(mut self, format: &'a str) -> Self {
+        self.format = Some(format);
+        self
+    }
+
+    /// Text input flags, e.g. `ENTER_RETURNS_TRUE` or `READ_ONLY`.
+    pub fn flags(mut self, flags: InputTextFlags) -> Self {
+        self.flags = flags;
+        self
+    }
+
+    /// Submit the input. Returns true if the value changed.
+    pub fn build(self, ui: &Ui, v: &mut T) -> bool {
+        self.build_array(ui, slice::from_mut(v))
+    }
+
+    /// Submit one input per element of `v`. Returns true if any value changed.
+    pub fn build_array(self, ui: &Ui, v: &mut [T]) -> bool {
+        ui.input_scalar_n(self.label, v, self.step, self.step_fast, self.format, self.flags)
+    }
+
+    /// Like `build()`, but fails on a NUL byte in the label or an invalid format.
+    pub fn try_build(self, ui: &Ui, v: &mut T) -> Result<bool, ImGuiError> {
+        self.try_build_array(ui, slice::from_mut(v))
+    }
+
+    /// Like `build_array()`, but fails on a NUL byte in the label or an invalid format.
+    pub fn try_build_array(self, ui: &Ui, v: &mut [T]) -> Result<bool, ImGuiError> {
+        check_nul(self.label)?;
+        if let Some(format) = self.format {
+            check_format(format, data_type_id::<T>())?;
+        }
+        Ok(self.build_array(ui, v))
+    }
+}
+
+/// Vertical slider for any numeric type, e.g. a mixer fader.
+///
+/// ```ignore
+/// for (i, level) in levels.iter_mut().enumerate() {
+///     let _id = ui.push_id_int(i as i32);
+///     VSlider::new("##fader", [18.0, 160.0], 0.0..=1.0).build(&ui, level);
+///     ui.same_line();
+/// }
+/// ```
+#[must_use = "call `build()` to submit the slider"]
+pub struct VSlider<'a, T: DataType> {
+    label: &'a str,
+    size: [f32; 2],
+    range: RangeInclusive<T>,
+    format: Option<&'a str>,
+    flags: SliderFlags,
+}
+
+impl<'a, T: DataType> VSlider<'a, T> {
+    /// A vertical slider of the given size editing values within `range`,
+    /// clamped as in `Slider::new()`.
+    pub fn new(label: &'a str, size: [f32; 2], range: RangeInclusive<T>) -> Self {
+        Self {
+            label,
+            size,
+            range,
+            format: None,
+            flags: SliderFlags::empty(),
+        }
+    }
+
+    /// Printf format of the value, e.g. `"%.2f"`.
+    pub fn format(mut self, format: &'a str) -> Self {
+        self.format = Some(format);
+        self
+    }
+
+    /// Slider flags.
+    pub fn flags(mut self, flags: SliderFlags) -> Self {
+        self.flags = flags;
+        self
+    }
+
+    /// Submit the slider. Returns true if the value changed.
+    pub fn build(self, ui: &Ui, v: &mut T) -> bool {
+        ui.v_slider_scalar(self.label, self.size, v, self.range, self.format, self.flags)
+    }
+
+    /// Like `build()`, but fails on a NUL byte in the label, an invalid
+    /// format, a range `build()` would clamp or `SliderFlags::WRAP_AROUND`.
+    pub fn try_build(self, ui: &Ui, v: &mut T) -> Result<bool, ImGuiError> {
+        check_nul(self.label)?;
+        if let Some(format) = self.format {
+            check_format(format, data_type_id::<T>())?;
+        }
+        check_slider_range(&self.range)?;
+        check_slider_flags(self.flags)?;
+        Ok(self.build(ui, v))
+    }
+}
+
+/// Slider editing an angle in radians, shown in degrees.
+///
+/// ```ignore
+/// AngleSlider::new("Rotation")
+///     .range_degrees(0.0..=360.0)
+///     .build(&ui, &mut rotation);
+/// ```
+#[must_use = "call `build()` to submit the slider"]
+pub struct AngleSlider<'a> {
+    label: &'a str,
+    range_degrees: RangeInclusive<f32>,
+    format: Option<&'a str>,
+    flags: SliderFlags,
+}
+
+impl<'a> AngleSlider<'a> {
+    /// An angle slider from -360 to 360 degrees.
+    pub fn new(label: &'a str) -> Self {
+        Self {
+            label,
+            range_degrees: -360.0..=360.0,
+            format: None,
+            flags: SliderFlags::empty(),
+        }
+    }
+
+    /// Range of the slider in degrees, clamped to half of `f32`'s limits.
+    pub fn range_degrees(mut self, range: RangeInclusive<f32>) -> Self {
+        self.range_degrees = range;
+        self
+    }
+
+    /// Printf format of the angle in degrees. Defaults to `"%.0f deg"`.
+    pub fn format(mut self, format: &'a str) -> Self {
+        self.format = Some(format);
+        self
+    }
+
+    /// Slider flags.
+    pub fn flags(mut self, flags: SliderFlags) -> Self {
+        self.flags = flags;
+        self
+    }
+
+    /// Submit the slider. Returns true if the angle changed.
+    pub fn build(self, _ui: &Ui, v_rad: &mut f32) -> bool {
+        let (label, label_end) = str_range(self.label);
+        let format = valid_format(self.format, FLOAT);
+        let (format, format_end) = format.map_or((ptr::null(), ptr::null()), str_range);
+        let (min, max) = slider_range(self.range_degrees).into_inner();
+        unsafe {
+            ffi::imgui_slider_angle(
+                label,
+                label_end,
+                v_rad,
+                min,
+                max,
+                format,
+                format_end,
+                (self.flags - SliderFlags::WRAP_AROUND).bits(),
+            ) != 0
+        }
+    }
+
+    /// Like `build()`, but fails on a NUL byte in the label, an invalid
+    /// format, a range `build()` would clamp or `SliderFlags::WRAP_AROUND`.
+    pub fn try_build(self, ui: &Ui, v_rad: &mut f32) -> Result<bool, ImGuiError> {
+        check_nul(self.label)?;
+        if let Some(format) = self.format {
+            check_format(format, FLOAT)?;
+        }
+        check_slider_range(&self.range_degrees)?;
+        check_slider_flags(self.flags)?;
+        Ok(self.build(ui, v_rad))
+    }
+}
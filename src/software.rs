@@ -0,0 +1,370 @@
+//! A CPU rasterizer for ImGui's draw data.
+//!
+//! It follows the OpenGL3 backend's pipeline: triangles are clipped to
+//! their command's scissor rectangle, vertex colors are interpolated and
+//! multiplied with a bilinear texture sample, and the result is blended
+//! with `SRC_ALPHA, ONE_MINUS_SRC_ALPHA` for color and `ONE,
+//! ONE_MINUS_SRC_ALPHA` for alpha. Pixels are covered using the top-left
+//! rule, so triangles sharing an edge never blend twice.
+
+use std::collections::HashMap;
+use std::os::raw::c_int;
+use std::{ptr, slice};
+
+use crate::{ffi, DrawData, DrawVert, RgbaImage, TextureId};
+
+/// Renders ImGui frames into an `RgbaImage` without a GPU, e.g. on CI
+/// machines.
+///
+/// Text and widgets sample ImGui's font atlas, which is read directly. Other
+/// textures shown with `image()` must be registered with their pixels;
+/// unknown textures are drawn as if they were opaque white.
+///
+/// ```ignore
+/// let software = SoftwareRenderer::new();
+/// let ui = imgui.new_frame();
+/// ui.window("Stats").build(|| ui.text("Hello"));
+/// let frame = ui.render_software(&software);
+/// assert_eq!(frame.width(), 800);
+/// ```
+#[derive(Debug, Default)]
+pub struct SoftwareRenderer {
+    textures: HashMap<TextureId, RgbaImage>,
+}
+
+impl SoftwareRenderer {
+    /// A renderer with no textures besides the font atlas.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Use `image` wherever `id` is drawn. Returns the image previously
+    /// registered for `id`.
+    pub fn register_texture(&mut self, id: TextureId, image: RgbaImage) -> Option<RgbaImage> {
+        self.textures.insert(id, image)
+    }
+
+    /// Forget the image registered for `id`.
+    pub fn remove_texture(&mut self, id: TextureId) -> Option<RgbaImage> {
+        self.textures.remove(&id)
+    }
+
+    /// Rasterize `draw_data` onto a transparent image of the display's
+    /// framebuffer size.
+    pub fn render(&self, draw_data: &DrawData) -> RgbaImage {
+        let [width, height] = draw_data.framebuffer_size();
+        let mut image = RgbaImage::new(width, height);
+        self.render_into(draw_data, &mut image);
+        image
+    }
+
+    /// Rasterize `draw_data` over the contents of `target`, stretched to
+    /// cover it.
+    pub fn render_into(&self, draw_data: &DrawData, target: &mut RgbaImage) {
+        let [display_width, display_height] = draw_data.display_size;
+        if display_width <= 0.0 || display_height <= 0.0 || target.width() == 0 || target.height() == 0 {
+            return;
+        }
+        let scale = [
+            target.width() as f32 / display_width,
+            target.height() as f32 / display_height,
+        ];
+        let origin = draw_data.display_pos;
+        let to_pixels = |p: [f32; 2]| [(p[0] - origin[0]) * scale[0], (p[1] - origin[1]) * scale[1]];
+
+        // Safety: `DrawData` borrows the `ImGui` context, so the atlas cannot
+        // change while it is alive.
+        let (font_id, font_atlas) = unsafe { font_atlas() };
+        let mut canvas = Canvas::new(target);
+
+        for list in draw_data.draw_lists() {
+            for cmd in list.commands() {
+                let min = to_pixels([cmd.clip_rect[0], cmd.clip_rect[1]]);
+                let max = to_pixels([cmd.clip_rect[2], cmd.clip_rect[3]]);
+                if max[0] <= min[0] || max[1] <= min[1] {
+                    continue;
+                }
+                // Truncated like the backend's glScissor() rectangle
+                let clip = [
+                    min[0] as i32,
+                    min[1] as i32,
+                    min[0] as i32 + (max[0] - min[0]) as i32,
+                    min[1] as i32 + (max[1] - min[1]) as i32,
+                ];
+                let texture = if cmd.texture == font_id {
+                    font_atlas
+                } else {
+                    self.textures.get(&cmd.texture).map(Texture::from)
+                };
+                for triangle in list.triangles(cmd) {
+                    canvas.triangle(triangle.map(|v| DrawVert { pos: to_pixels(v.pos), ..v }), clip, texture);
+                }
+            }
+        }
+    }
+}
+
+/// The font atlas's texture ID and pixels.
+///
+/// # Safety
+/// The pixels are borrowed from ImGui and must not outlive the atlas.
+unsafe fn font_atlas<'a>() -> (TextureId, Option<Texture<'a>>) {
+    let mut pixels: *const u8 = ptr::null();
+    let (mut width, mut height): (c_int, c_int) = (0, 0);
+    let id = ffi::imgui_fonts_tex_data_rgba32(&mut pixels, &mut width, &mut height);
+    let texture = if pixels.is_null() || width <= 0 || height <= 0 {
+        None
+    } else {
+        Some(Texture {
+            width: width as u32,
+            height: height as u32,
+            pixels: slice::from_raw_parts(pixels, width as usize * height as usize * 4),
+        })
+    };
+    (TextureId::new(id), texture)
+}
+
+/// RGBA texels sampled like a `GL_LINEAR`, `GL_CLAMP_TO_EDGE` texture.
+#[derive(Clone, Copy)]
+struct Texture<'a> {
+    width: u32,
+    height: u32,
+    pixels: &'a [u8],
+}
+
+impl<'a> From<&'a RgbaImage> for Texture<'a> {
+    fn from(image: &'a RgbaImage) -> Self {
+        Self {
+            width: image.width(),
+            height: image.height(),
+            pixels: image.as_raw(),
+        }
+    }
+}
+
+impl Texture<'_> {
+    fn texel(&self, x: i64, y: i64) -> [f32; 4] {
+        let x = x.clamp(0, self.width as i64 - 1) as usize;
+        let y = y.clamp(0, self.height as i64 - 1) as usize;
+        let i = (y * self.width as usize + x) * 4;
+        let p = &self.pixels[i..i + 4];
+        [p[0] as f32, p[1] as f32, p[2] as f32, p[3] as f32].map(|c| c / 255.0)
+    }
+
+    fn sample(&self, uv: [f32; 2]) -> [f32; 4] {
+        if self.width == 0 || self.height == 0 {
+            return [1.0; 4];
+        }
+        let x = uv[0] * self.width as f32 - 0.5;
+        let y = uv[1] * self.height as f32 - 0.5;
+        let (x0, y0) = (x.floor(), y.floor());
+        let (fx, fy) = (x - x0, y - y0);
+        let (x0, y0) = (x0 as i64, y0 as i64);
+        let top = lerp4(self.texel(x0, y0), self.texel(x0 + 1, y0), fx);
+        let bottom = lerp4(self.texel(x0, y0 + 1), self.texel(x0 + 1, y0 + 1), fx);
+        lerp4(top, bottom, fy)
+    }
+}
+
+fn lerp4(a: [f32; 4], b: [f32; 4], t: f32) -> [f32; 4] {
+    [0, 1, 2, 3].map(|i| a[i] + (b[i] - a[i]) * t)
+}
+
+/// Twice the signed area of `a, b, p`; positive when `p` is right of `a -> b`
+/// with y pointing down.
+fn edge(a: [f32; 2], b: [f32; 2], p: [f32; 2]) -> f32 {
+    (b[0] - a[0]) * (p[1] - a[1]) - (b[1] - a[1]) * (p[0] - a[0])
+}
+
+/// Whether pixels exactly on `a -> b` belong to the triangle: the top edge
+/// or a left edge, for the winding `edge()` makes positive.
+fn is_top_left(a: [f32; 2], b: [f32; 2]) -> bool {
+    let (dx, dy) = (b[0] - a[0], b[1] - a[1]);
+    (dy == 0.0 && dx > 0.0) || dy < 0.0
+}
+
+struct Canvas<'a> {
+    width: i32,
+    height: i32,
+    pixels: &'a mut [u8],
+}
+
+impl<'a> Canvas<'a> {
+    fn new(image: &'a mut RgbaImage) -> Self {
+        Self {
+            width: image.width() as i32,
+            height: image.height() as i32,
+            pixels: image.as_raw_mut(),
+        }
+    }
+
+    /// Fill a triangle given in pixel coordinates, limited to `clip`
+    /// (`[x1, y1, x2, y2]`, exclusive of `x2` and `y2`).
+    fn triangle(&mut self, mut v: [DrawVert; 3], clip: [i32; 4], texture: Option<Texture>) {
+        let mut area = edge(v[0].pos, v[1].pos, v[2].pos);
+        if area == 0.0 || !area.is_finite() {
+            return;
+        }
+        // The backend does not cull, so both windings are drawn
+        if area < 0.0 {
+            v.swap(1, 2);
+            area = -area;
+        }
+        let [p0, p1, p2] = [v[0].pos, v[1].pos, v[2].pos];
+        let colors = v.map(|v| v.color().map(|c| c as f32 / 255.0));
+        let top_left = [is_top_left(p1, p2), is_top_left(p2, p0), is_top_left(p0, p1)];
+
+        let x_min = (p0[0].min(p1[0]).min(p2[0]).floor() as i32).max(clip[0]).max(0);
+        let y_min = (p0[1].min(p1[1]).min(p2[1]).floor() as i32).max(clip[1]).max(0);
+        let x_max = (p0[0].max(p1[0]).max(p2[0]).ceil() as i32).min(clip[2]).min(self.width);
+        let y_max = (p0[1].max(p1[1]).max(p2[1]).ceil() as i32).min(clip[3]).min(self.height);
+
+        for y in y_min..y_max {
+            for x in x_min..x_max {
+                let p = [x as f32 + 0.5, y as f32 + 0.5];
+                let w = [edge(p1, p2, p), edge(p2, p0, p), edge(p0, p1, p)];
+                if (0..3).any(|i| w[i] < 0.0 || (w[i] == 0.0 && !top_left[i])) {
+                    continue;
+                }
+                let l = w.map(|w| w / area);
+                let interpolate = |a: f32, b: f32, c: f32| a * l[0] + b * l[1] + c * l[2];
+                let mut src = [0, 1, 2, 3].map(|i| interpolate(colors[0][i], colors[1][i], colors[2][i]));
+                if let Some(texture) = texture {
+                    let uv = [0, 1].map(|i| interpolate(v[0].uv[i], v[1].uv[i], v[2].uv[i]));
+                    let texel = texture.sample(uv);
+                    src = [0, 1, 2, 3].map(|i| src[i] * texel[i]);
+                }
+                self.blend(x, y, src);
+            }
+        }
+    }
+
+    fn blend(&mut self, x: i32, y: i32, src: [f32; 4]) {
+        let i = (y as usize * self.width as usize + x as usize) * 4;
+        let dst = &mut self.pixels[i..i + 4];
+        let a = src[3].clamp(0.0, 1.0);
+        for c in 0..3 {
+            let d = dst[c] as f32 / 255.0;
+            dst[c] = to_byte(src[c] * a + d * (1.0 - a));
+        }
+        let d = dst[3] as f32 / 255.0;
+        dst[3] = to_byte(a + d * (1.0 - a));
+    }
+}
+
+fn to_byte(v: f32) -> u8 {
+    (v.clamp(0.0, 1.0) * 255.0 + 0.5) as u8
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FULL: [i32; 4] = [0, 0, i32::MAX, i32::MAX];
+
+    fn vert(pos: [f32; 2], uv: [f32; 2], color: [u8; 4]) -> DrawVert {
+        DrawVert {
+            pos,
+            uv,
+            col: u32::from_le_bytes(color),
+        }
+    }
+
+    fn solid(pos: [[f32; 2]; 3], color: [u8; 4]) -> [DrawVert; 3] {
+        pos.map(|p| vert(p, [0.0; 2], color))
+    }
+
+    fn filled(width: u32, height: u32, color: [u8; 4]) -> RgbaImage {
+        let mut image = RgbaImage::new(width, height);
+        for y in 0..height {
+            for x in 0..width {
+                image.put_pixel(x, y, color);
+            }
+        }
+        image
+    }
+
+    #[test]
+    fn solid_triangle_covers_pixel_centers_inside_it() {
+        let mut image = RgbaImage::new(8, 8);
+        let red = [255, 0, 0, 255];
+        Canvas::new(&mut image).triangle(solid([[0.0, 0.0], [8.0, 0.0], [0.0, 8.0]], red), FULL, None);
+
+        for (x, y) in [(0, 0), (1, 1), (3, 3), (6, 0), (0, 6)] {
+            assert_eq!(image.get_pixel(x, y), red, "pixel {}, {}", x, y);
+        }
+        for (x, y) in [(4, 4), (6, 6), (7, 1), (1, 7), (7, 7)] {
+            assert_eq!(image.get_pixel(x, y), [0; 4], "pixel {}, {}", x, y);
+        }
+    }
+
+    #[test]
+    fn shared_edges_are_drawn_once() {
+        let mut image = RgbaImage::new(4, 4);
+        let color = [255, 255, 255, 128];
+        let mut canvas = Canvas::new(&mut image);
+        canvas.triangle(solid([[0.0, 0.0], [4.0, 0.0], [0.0, 4.0]], color), FULL, None);
+        canvas.triangle(solid([[4.0, 0.0], [4.0, 4.0], [0.0, 4.0]], color), FULL, None);
+
+        let once = image.get_pixel(0, 0);
+        for y in 0..4 {
+            for x in 0..4 {
+                assert_eq!(image.get_pixel(x, y), once, "pixel {}, {}", x, y);
+            }
+        }
+    }
+
+    #[test]
+    fn scissor_clips_to_its_rectangle() {
+        let mut image = RgbaImage::new(8, 8);
+        let green = [0, 255, 0, 255];
+        let clip = [2, 2, 6, 5];
+        let mut canvas = Canvas::new(&mut image);
+        canvas.triangle(solid([[0.0, 0.0], [8.0, 0.0], [0.0, 8.0]], green), clip, None);
+        canvas.triangle(solid([[8.0, 0.0], [8.0, 8.0], [0.0, 8.0]], green), clip, None);
+
+        for (x, y) in [(2, 2), (5, 2), (2, 4), (5, 4)] {
+            assert_eq!(image.get_pixel(x, y), green, "pixel {}, {}", x, y);
+        }
+        for (x, y) in [(1, 2), (2, 1), (6, 2), (5, 5), (6, 5), (1, 1)] {
+            assert_eq!(image.get_pixel(x, y), [0; 4], "pixel {}, {}", x, y);
+        }
+    }
+
+    #[test]
+    fn half_alpha_blends_over_an_opaque_background() {
+        let mut image = filled(4, 4, [0, 0, 255, 255]);
+        let half_red = [255, 0, 0, 128];
+        Canvas::new(&mut image).triangle(solid([[0.0, 0.0], [4.0, 0.0], [0.0, 4.0]], half_red), FULL, None);
+
+        assert_eq!(image.get_pixel(0, 0), [128, 0, 127, 255]);
+        assert_eq!(image.get_pixel(3, 3), [0, 0, 255, 255]);
+    }
+
+    #[test]
+    fn font_atlas_is_sampled_at_its_uvs() {
+        // A bare context, whose atlas builds ImGui's default font on demand
+        let ctx = unsafe { ffi::imgui_create_context() };
+        let (_, atlas) = unsafe { font_atlas() };
+        let atlas = atlas.expect("the font atlas is built");
+        let texel_uv = |i: usize| {
+            let (x, y) = (i as u32 % atlas.width, i as u32 / atlas.width);
+            [(x as f32 + 0.5) / atlas.width as f32, (y as f32 + 0.5) / atlas.height as f32]
+        };
+        let alphas: Vec<u8> = atlas.pixels.chunks_exact(4).map(|p| p[3]).collect();
+        let opaque = alphas.iter().position(|&a| a == 255).expect("an opaque texel");
+        let clear = alphas.iter().position(|&a| a == 0).expect("a transparent texel");
+
+        let mut image = RgbaImage::new(4, 4);
+        let mut canvas = Canvas::new(&mut image);
+        let red = [255, 0, 0, 255];
+        let at = |pos: [[f32; 2]; 3], uv: [f32; 2]| pos.map(|p| vert(p, uv, red));
+        canvas.triangle(at([[0.0, 0.0], [2.0, 0.0], [0.0, 2.0]], texel_uv(opaque)), FULL, Some(atlas));
+        canvas.triangle(at([[2.0, 2.0], [4.0, 2.0], [2.0, 4.0]], texel_uv(clear)), FULL, Some(atlas));
+
+        // ImGui's atlas is white with the coverage in alpha
+        assert_eq!(image.get_pixel(0, 0), red);
+        assert_eq!(image.get_pixel(2, 2), [0; 4]);
+        unsafe { ffi::imgui_destroy_context(ctx) };
+    }
+}
@@ -0,0 +1,29 @@
+//! Conversion of Rust strings to the string ranges expected by the C wrapper.
+//!
+//! Strings are passed as `[begin, end)` pointer pairs following ImGui's
+//! `text_end` convention, so no allocation is needed per call. A string is
+//! cut at its first NUL byte, as a C string would be, so a label built from
+//! user data never aborts the application. The `try_*` variants report the
+//! NUL byte as `ImGuiError::InteriorNul` instead.
+
+use std::os::raw::c_char;
+
+use crate::ImGuiError;
+
+/// Begin/end pointers for `s`, truncated at the first NUL byte.
+pub(crate) fn str_range(s: &str) -> (*const c_char, *const c_char) {
+    let bytes = s.as_bytes();
+    let len = bytes.iter().position(|&b| b == 0).unwrap_or(bytes.len());
+    (
+        bytes.as_ptr() as *const c_char,
+        bytes[len..].as_ptr() as *const c_char,
+    )
+}
+
+/// Fail if `s` contains a NUL byte, which would otherwise be truncated.
+pub(crate) fn check_nul(s: &str) -> Result<(), ImGuiError> {
+    match s.bytes().position(|b| b == 0) {
+        Some(position) => Err(ImGuiError::InteriorNul { position }),
+        None => Ok(()),
+    }
+}
@@ -0,0 +1,522 @@
+//! Style colors, style variables and the persistent `Style`.
+
+use std::mem::{self, MaybeUninit};
+use std::ops::{Index, IndexMut};
+
+use crate::{ffi, ImGuiError};
+
+/// Style color index (`ImGuiCol`) for `push_style_color()`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(i32)]
+pub enum StyleColor {
+    Text,
+    TextDisabled,
+    WindowBg,
+    ChildBg,
+    PopupBg,
+    Border,
+    BorderShadow,
+    FrameBg,
+    FrameBgHovered,
+    FrameBgActive,
+    TitleBg,
+    TitleBgActive,
+    TitleBgCollapsed,
+    MenuBarBg,
+    ScrollbarBg,
+    ScrollbarGrab,
+    ScrollbarGrabHovered,
+    ScrollbarGrabActive,
+    CheckMark,
+    SliderGrab,
+    SliderGrabActive,
+    Button,
+    ButtonHovered,
+    ButtonActive,
+    Header,
+    HeaderHovered,
+    HeaderActive,
+    Separator,
+    SeparatorHovered,
+    SeparatorActive,
+    ResizeGrip,
+    ResizeGripHovered,
+    ResizeGripActive,
+    TabHovered,
+    Tab,
+    TabSelected,
+    TabSelectedOverline,
+    TabDimmed,
+    TabDimmedSelected,
+    TabDimmedSelectedOverline,
+    PlotLines,
+    PlotLinesHovered,
+    PlotHistogram,
+    PlotHistogramHovered,
+    TableHeaderBg,
+    TableBorderStrong,
+    TableBorderLight,
+    TableRowBg,
+    TableRowBgAlt,
+    TextLink,
+    TextSelectedBg,
+    DragDropTarget,
+    NavCursor,
+    NavWindowingHighlight,
+    NavWindowingDimBg,
+    ModalWindowDimBg,
+}
+
+impl StyleColor {
+    /// Number of style colors (`ImGuiCol_COUNT`).
+    pub const COUNT: usize = 56;
+
+    /// Every style color, in `ImGuiCol` order.
+    pub const ALL: [StyleColor; StyleColor::COUNT] = [
+        StyleColor::Text,
+        StyleColor::TextDisabled,
+        StyleColor::WindowBg,
+        StyleColor::ChildBg,
+        StyleColor::PopupBg,
+        StyleColor::Border,
+        StyleColor::BorderShadow,
+        StyleColor::FrameBg,
+        StyleColor::FrameBgHovered,
+        StyleColor::FrameBgActive,
+        StyleColor::TitleBg,
+        StyleColor::TitleBgActive,
+        StyleColor::TitleBgCollapsed,
+        StyleColor::MenuBarBg,
+        StyleColor::ScrollbarBg,
+        StyleColor::ScrollbarGrab,
+        StyleColor::ScrollbarGrabHovered,
+        StyleColor::ScrollbarGrabActive,
+        StyleColor::CheckMark,
+        StyleColor::SliderGrab,
+        StyleColor::SliderGrabActive,
+        StyleColor::Button,
+        StyleColor::ButtonHovered,
+        StyleColor::ButtonActive,
+        StyleColor::Header,
+        StyleColor::HeaderHovered,
+        StyleColor::HeaderActive,
+        StyleColor::Separator,
+        StyleColor::SeparatorHovered,
+        StyleColor::SeparatorActive,
+        StyleColor::ResizeGrip,
+        StyleColor::ResizeGripHovered,
+        StyleColor::ResizeGripActive,
+        StyleColor::TabHovered,
+        StyleColor::Tab,
+        StyleColor::TabSelected,
+        StyleColor::TabSelectedOverline,
+        StyleColor::TabDimmed,
+        StyleColor::TabDimmedSelected,
+        StyleColor::TabDimmedSelectedOverline,
+        StyleColor::PlotLines,
+        StyleColor::PlotLinesHovered,
+        StyleColor::PlotHistogram,
+        StyleColor::PlotHistogramHovered,
+        StyleColor::TableHeaderBg,
+        StyleColor::TableBorderStrong,
+        StyleColor::TableBorderLight,
+        StyleColor::TableRowBg,
+        StyleColor::TableRowBgAlt,
+        StyleColor::TextLink,
+        StyleColor::TextSelectedBg,
+        StyleColor::DragDropTarget,
+        StyleColor::NavCursor,
+        StyleColor::NavWindowingHighlight,
+        StyleColor::NavWindowingDimBg,
+        StyleColor::ModalWindowDimBg,
+    ];
+}
+
+/// Style variable (`ImGuiStyleVar`) with the value to push.
+///
+/// Each variant carries the type ImGui expects for that variable, so
+/// `push_style_var()` always calls the matching float or vector overload.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum StyleVar {
+    Alpha(f32),
+    DisabledAlpha(f32),
+    WindowPadding([f32; 2]),
+    WindowRounding(f32),
+    WindowBorderSize(f32),
+    WindowMinSize([f32; 2]),
+    WindowTitleAlign([f32; 2]),
+    ChildRounding(f32),
+    ChildBorderSize(f32),
+    PopupRounding(f32),
+    PopupBorderSize(f32),
+    FramePadding([f32; 2]),
+    FrameRounding(f32),
+    FrameBorderSize(f32),
+    ItemSpacing([f32; 2]),
+    ItemInnerSpacing([f32; 2]),
+    IndentSpacing(f32),
+    CellPadding([f32; 2]),
+    ScrollbarSize(f32),
+    ScrollbarRounding(f32),
+    GrabMinSize(f32),
+    GrabRounding(f32),
+    TabRounding(f32),
+    TabBorderSize(f32),
+    TabBarBorderSize(f32),
+    TabBarOverlineSize(f32),
+    TableAngledHeadersAngle(f32),
+    TableAngledHeadersTextAlign([f32; 2]),
+    ButtonTextAlign([f32; 2]),
+    SelectableTextAlign([f32; 2]),
+    SeparatorTextBorderSize(f32),
+    SeparatorTextAlign([f32; 2]),
+    SeparatorTextPadding([f32; 2]),
+}
+
+/// Value of a style variable, as passed to the C wrapper.
+pub(crate) enum StyleVarValue {
+    Float(f32),
+    Vec2([f32; 2]),
+}
+
+impl StyleVar {
+    /// The `ImGuiStyleVar` index and value of this variable.
+    pub(crate) fn split(self) -> (i32, StyleVarValue) {
+        use StyleVar::*;
+        use StyleVarValue::{Float, Vec2};
+
+        match self {
+            Alpha(v) => (0, Float(v)),
+            DisabledAlpha(v) => (1, Float(v)),
+            WindowPadding(v) => (2, Vec2(v)),
+            WindowRounding(v) => (3, Float(v)),
+            WindowBorderSize(v) => (4, Float(v)),
+            WindowMinSize(v) => (5, Vec2(v)),
+            WindowTitleAlign(v) => (6, Vec2(v)),
+            ChildRounding(v) => (7, Float(v)),
+            ChildBorderSize(v) => (8, Float(v)),
+            PopupRounding(v) => (9, Float(v)),
+            PopupBorderSize(v) => (10, Float(v)),
+            FramePadding(v) => (11, Vec2(v)),
+            FrameRounding(v) => (12, Float(v)),
+            FrameBorderSize(v) => (13, Float(v)),
+            ItemSpacing(v) => (14, Vec2(v)),
+            ItemInnerSpacing(v) => (15, Vec2(v)),
+            IndentSpacing(v) => (16, Float(v)),
+            CellPadding(v) => (17, Vec2(v)),
+            ScrollbarSize(v) => (18, Float(v)),
+            ScrollbarRounding(v) => (19, Float(v)),
+            GrabMinSize(v) => (20, Float(v)),
+            GrabRounding(v) => (21, Float(v)),
+            TabRounding(v) => (22, Float(v)),
+            TabBorderSize(v) => (23, Float(v)),
+            TabBarBorderSize(v) => (24, Float(v)),
+            TabBarOverlineSize(v) => (25, Float(v)),
+            TableAngledHeadersAngle(v) => (26, Float(v)),
+            TableAngledHeadersTextAlign(v) => (27, Vec2(v)),
+            ButtonTextAlign(v) => (28, Vec2(v)),
+            SelectableTextAlign(v) => (29, Vec2(v)),
+            SeparatorTextBorderSize(v) => (30, Float(v)),
+            SeparatorTextAlign(v) => (31, Vec2(v)),
+            SeparatorTextPadding(v) => (32, Vec2(v)),
+        }
+    }
+}
+
+/// A cardinal direction (`ImGuiDir`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(i32)]
+pub enum Direction {
+    None = -1,
+    Left = 0,
+    Right = 1,
+    Up = 2,
+    Down = 3,
+}
+
+/// The persistent style (`ImGuiStyle`).
+///
+/// Fetch it with `ImGui::style()`, modify it and apply it with
+/// `ImGui::set_style()`. Colors are indexed by `StyleColor`:
+///
+/// ```ignore
+/// let mut style = Style::light();
+/// style.frame_rounding = 4.0;
+/// style[StyleColor::Button] = [0.2, 0.4, 0.8, 1.0];
+/// imgui.set_style(&style)?;
+/// ```
+///
+/// The layout matches `ImGuiStyle` field for field, so it is copied to and
+/// from ImGui directly.
+///
+/// With the `serde` feature the style can be serialized; colors are written
+/// as a table keyed by `StyleColor` name, and missing fields or colors keep
+/// their `Style::default()` value when deserializing.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[repr(C)]
+pub struct Style {
+    /// Global alpha applied to everything.
+    pub alpha: f32,
+    /// Additional alpha multiplier for disabled items.
+    pub disabled_alpha: f32,
+    /// Padding within a window.
+    pub window_padding: [f32; 2],
+    /// Radius of window corners.
+    pub window_rounding: f32,
+    /// Thickness of the border around windows.
+    pub window_border_size: f32,
+    /// Minimum window size.
+    pub window_min_size: [f32; 2],
+    /// Alignment of the title bar text.
+    pub window_title_align: [f32; 2],
+    /// Side of the collapse button in the title bar (`None`, `Left` or `Right`).
+    pub window_menu_button_position: Direction,
+    /// Radius of child window corners.
+    pub child_rounding: f32,
+    /// Thickness of the border around child windows.
+    pub child_border_size: f32,
+    /// Radius of popup window corners.
+    pub popup_rounding: f32,
+    /// Thickness of the border around popup and tooltip windows.
+    pub popup_border_size: f32,
+    /// Padding within a framed rectangle (used by most widgets).
+    pub frame_padding: [f32; 2],
+    /// Radius of frame corners.
+    pub frame_rounding: f32,
+    /// Thickness of the border around frames.
+    pub frame_border_size: f32,
+    /// Horizontal and vertical spacing between widgets and lines.
+    pub item_spacing: [f32; 2],
+    /// Spacing between the elements of a composed widget.
+    pub item_inner_spacing: [f32; 2],
+    /// Padding within a table cell.
+    pub cell_padding: [f32; 2],
+    /// Extra reactive area around items for touch input.
+    pub touch_extra_padding: [f32; 2],
+    /// Horizontal indentation of tree nodes.
+    pub indent_spacing: f32,
+    /// Minimum horizontal spacing between two columns.
+    pub columns_min_spacing: f32,
+    /// Width of the vertical scrollbar, height of the horizontal one.
+    pub scrollbar_size: f32,
+    /// Radius of scrollbar grab corners.
+    pub scrollbar_rounding: f32,
+    /// Minimum size of a slider or scrollbar grab.
+    pub grab_min_size: f32,
+    /// Radius of slider grab corners.
+    pub grab_rounding: f32,
+    /// Dead zone around zero on logarithmic sliders that cross zero.
+    pub log_slider_deadzone: f32,
+    /// Radius of the upper corners of a tab.
+    pub tab_rounding: f32,
+    /// Thickness of the border around tabs.
+    pub tab_border_size: f32,
+    /// Minimum tab width for the close button to appear when hovered.
+    pub tab_min_width_for_close_button: f32,
+    /// Thickness of the tab bar separator.
+    pub tab_bar_border_size: f32,
+    /// Thickness of the overline of the selected tab.
+    pub tab_bar_overline_size: f32,
+    /// Angle of angled table headers, in radians.
+    pub table_angled_headers_angle: f32,
+    /// Alignment of angled table headers within the cell.
+    pub table_angled_headers_text_align: [f32; 2],
+    /// Side of the color button in `color_edit4` (`Left` or `Right`).
+    pub color_button_position: Direction,
+    /// Alignment of button text.
+    pub button_text_align: [f32; 2],
+    /// Alignment of selectable text.
+    pub selectable_text_align: [f32; 2],
+    /// Thickness of the line in `separator_text`.
+    pub separator_text_border_size: f32,
+    /// Alignment of the text in `separator_text`.
+    pub separator_text_align: [f32; 2],
+    /// Padding around the text in `separator_text`.
+    pub separator_text_padding: [f32; 2],
+    /// Amount of a window kept visible when moved near the display edges.
+    pub display_window_padding: [f32; 2],
+    /// Margin kept free of content at the display edges.
+    pub display_safe_area_padding: [f32; 2],
+    /// Scale of the software mouse cursor.
+    pub mouse_cursor_scale: f32,
+    /// Anti-alias lines and borders.
+    pub anti_aliased_lines: bool,
+    /// Anti-alias lines using textures where possible.
+    pub anti_aliased_lines_use_tex: bool,
+    /// Anti-alias the edges of filled shapes.
+    pub anti_aliased_fill: bool,
+    /// Tessellation tolerance for bezier curves.
+    pub curve_tessellation_tol: f32,
+    /// Maximum error allowed when tessellating circles and rounded corners.
+    pub circle_tessellation_max_error: f32,
+    /// Color table, indexed by `StyleColor`.
+    #[cfg_attr(feature = "serde", serde(with = "crate::theme::colors_by_name"))]
+    pub colors: [[f32; 4]; StyleColor::COUNT],
+    /// Delay before the mouse is considered stationary, in seconds.
+    pub hover_stationary_delay: f32,
+    /// Delay for short hover tooltips, in seconds.
+    pub hover_delay_short: f32,
+    /// Delay for normal hover tooltips, in seconds.
+    pub hover_delay_normal: f32,
+    /// Raw `ImGuiHoveredFlags` used for tooltips with the mouse.
+    pub hover_flags_for_tooltip_mouse: i32,
+    /// Raw `ImGuiHoveredFlags` used for tooltips with keyboard or gamepad navigation.
+    pub hover_flags_for_tooltip_nav: i32,
+}
+
+// Must match the `static_assert` in `imgui_wrapper.cpp`.
+const _: () = assert!(mem::size_of::<Style>() == 1156);
+
+impl Style {
+    /// ImGui's default sizes with the dark color preset.
+    pub fn dark() -> Self {
+        Self::with_preset(ffi::imgui_style_colors_dark)
+    }
+
+    /// ImGui's default sizes with the light color preset.
+    pub fn light() -> Self {
+        Self::with_preset(ffi::imgui_style_colors_light)
+    }
+
+    /// ImGui's default sizes with the classic color preset.
+    pub fn classic() -> Self {
+        Self::with_preset(ffi::imgui_style_colors_classic)
+    }
+
+    /// Replace the colors with the dark preset, keeping the sizes.
+    pub fn use_dark_colors(&mut self) {
+        unsafe { ffi::imgui_style_colors_dark(self) };
+    }
+
+    /// Replace the colors with the light preset, keeping the sizes.
+    pub fn use_light_colors(&mut self) {
+        unsafe { ffi::imgui_style_colors_light(self) };
+    }
+
+    /// Replace the colors with the classic preset, keeping the sizes.
+    pub fn use_classic_colors(&mut self) {
+        unsafe { ffi::imgui_style_colors_classic(self) };
+    }
+
+    /// Check the values ImGui asserts on when a frame starts, which would
+    /// abort the process.
+    ///
+    /// `alpha` must be between 0 and 1, `curve_tessellation_tol` and
+    /// `circle_tessellation_max_error` above 0, both `window_min_size`
+    /// components at least 1, `window_menu_button_position` `None`, `Left` or
+    /// `Right`, and `color_button_position` `Left` or `Right`.
+    pub fn validate(&self) -> Result<(), ImGuiError> {
+        let invalid = |message: String| Err(ImGuiError::InvalidStyle { message });
+        if !(0.0..=1.0).contains(&self.alpha) {
+            return invalid(format!("`alpha` is {}, expected 0 to 1", self.alpha));
+        }
+        if self.curve_tessellation_tol.is_nan() || self.curve_tessellation_tol <= 0.0 {
+            return invalid(format!("`curve_tessellation_tol` is {}, expected more than 0", self.curve_tessellation_tol));
+        }
+        if self.circle_tessellation_max_error.is_nan() || self.circle_tessellation_max_error <= 0.0 {
+            return invalid(format!(
+                "`circle_tessellation_max_error` is {}, expected more than 0",
+                self.circle_tessellation_max_error
+            ));
+        }
+        if self.window_min_size.iter().any(|v| v.is_nan() || *v < 1.0) {
+            return invalid(format!("`window_min_size` is {:?}, expected at least 1", self.window_min_size));
+        }
+        if !matches!(self.window_menu_button_position, Direction::None | Direction::Left | Direction::Right) {
+            return invalid(format!(
+                "`window_menu_button_position` is {:?}, expected None, Left or Right",
+                self.window_menu_button_position
+            ));
+        }
+        if !matches!(self.color_button_position, Direction::Left | Direction::Right) {
+            return invalid(format!(
+                "`color_button_position` is {:?}, expected Left or Right",
+                self.color_button_position
+            ));
+        }
+        Ok(())
+    }
+
+    /// Multiply every size by `factor`, as `ImGuiStyle::ScaleAllSizes` does.
+    pub fn scale_all_sizes(&mut self, factor: f32) {
+        unsafe { ffi::imgui_style_scale_all_sizes(self, factor) };
+    }
+
+    fn with_preset(colors: unsafe extern "C" fn(*mut Style)) -> Self {
+        let mut style = MaybeUninit::<Style>::uninit();
+        unsafe {
+            ffi::imgui_style_default(style.as_mut_ptr());
+            colors(style.as_mut_ptr());
+            style.assume_init()
+        }
+    }
+}
+
+impl Default for Style {
+    /// ImGui's default style, which uses the dark color preset.
+    fn default() -> Self {
+        Self::dark()
+    }
+}
+
+impl Index<StyleColor> for Style {
+    type Output = [f32; 4];
+
+    fn index(&self, color: StyleColor) -> &[f32; 4] {
+        &self.colors[color as usize]
+    }
+}
+
+impl IndexMut<StyleColor> for Style {
+    fn index_mut(&mut self, color: StyleColor) -> &mut [f32; 4] {
+        &mut self.colors[color as usize]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn presets_are_valid() {
+        for style in [Style::dark(), Style::light(), Style::classic()] {
+            assert_eq!(style.validate(), Ok(()));
+        }
+    }
+
+    #[test]
+    fn values_imgui_asserts_on_are_rejected() {
+        let cases: [fn(&mut Style); 9] = [
+            |s| s.alpha = 1.5,
+            |s| s.alpha = f32::NAN,
+            |s| s.curve_tessellation_tol = 0.0,
+            |s| s.circle_tessellation_max_error = -1.0,
+            |s| s.window_min_size = [0.5, 32.0],
+            |s| s.window_min_size = [32.0, f32::NAN],
+            |s| s.window_menu_button_position = Direction::Up,
+            |s| s.color_button_position = Direction::None,
+            |s| s.color_button_position = Direction::Down,
+        ];
+        for (i, edit) in cases.iter().enumerate() {
+            let mut style = Style::default();
+            edit(&mut style);
+            assert!(matches!(style.validate(), Err(ImGuiError::InvalidStyle { .. })), "case {}", i);
+        }
+    }
+
+    #[test]
+    fn boundary_values_are_accepted() {
+        let style = Style {
+            alpha: 0.0,
+            window_min_size: [1.0, 1.0],
+            window_menu_button_position: Direction::None,
+            color_button_position: Direction::Left,
+            ..Style::default()
+        };
+        assert_eq!(style.validate(), Ok(()));
+    }
+}
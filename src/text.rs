@@ -0,0 +1,40 @@
+//! Formatted text without a per-frame allocation.
+
+use std::cell::RefCell;
+use std::fmt::{self, Write};
+
+thread_local! {
+    static BUFFER: RefCell<String> = const { RefCell::new(String::new()) };
+}
+
+/// Format `args` into a buffer reused across calls and pass the result to `f`.
+///
+/// A nested call, e.g. from a `Display` impl that itself uses `text!`,
+/// formats into a fresh `String` instead.
+pub(crate) fn with_formatted<R>(args: fmt::Arguments, f: impl FnOnce(&str) -> R) -> R {
+    if let Some(text) = args.as_str() {
+        return f(text);
+    }
+    BUFFER.with(|buffer| match buffer.try_borrow_mut() {
+        Ok(mut buffer) => {
+            buffer.clear();
+            let _ = buffer.write_fmt(args);
+            f(&buffer)
+        }
+        Err(_) => f(&fmt::format(args)),
+    })
+}
+
+/// Display formatted text, like `ui.text(&format!(...))` but without
+/// allocating a new `String` each frame.
+///
+/// ```ignore
+/// text!(ui, "FPS: {:.1}", fps);
+/// text!(ui, "Shapes: {}", app.shapes().len());
+/// ```
+#[macro_export]
+macro_rules! text {
+    ($ui:expr, $($arg:tt)*) => {
+        $ui.text_fmt(::std::format_args!($($arg)*))
+    };
+}
@@ -0,0 +1,51 @@
+//! Texture handles for the image widgets.
+
+use wilhelm_renderer::core::Mesh;
+
+/// Texture shown by `image()` and `image_button()`: an `ImTextureID`, which
+/// the OpenGL3 backend interprets as a GL texture name.
+///
+/// Textures created through wilhelm_renderer are plain GL names, so they
+/// convert directly:
+///
+/// ```ignore
+/// let texture = generate_texture_from_image(&load_image("sprite.png"));
+/// ui.image(TextureId::from(texture), [64.0, 64.0], [0.0, 0.0], [1.0, 1.0], [1.0; 4], [0.0; 4]);
+/// ```
+///
+/// The texture must stay alive until the frame has been rendered.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TextureId(u64);
+
+impl TextureId {
+    /// Wrap a raw `ImTextureID` value.
+    pub const fn new(id: u64) -> Self {
+        Self(id)
+    }
+
+    /// Wrap an OpenGL texture name.
+    pub const fn from_gl(name: u32) -> Self {
+        Self(name as u64)
+    }
+
+    /// The texture of a wilhelm_renderer mesh, if it has one.
+    pub fn from_mesh(mesh: &Mesh) -> Option<Self> {
+        mesh.texture.map(Self::from_gl)
+    }
+
+    /// The raw `ImTextureID` value.
+    pub const fn id(self) -> u64 {
+        self.0
+    }
+
+    /// The OpenGL texture name.
+    pub const fn gl_name(self) -> u32 {
+        self.0 as u32
+    }
+}
+
+impl From<u32> for TextureId {
+    fn from(name: u32) -> Self {
+        Self::from_gl(name)
+    }
+}
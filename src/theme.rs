@@ -0,0 +1,219 @@
+//! Theme files: `Style` stored as TOML or JSON.
+//!
+//! The format is picked from the file extension (`.toml` or `.json`).
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+use crate::{ImGui, ImGuiError, Style, StyleColor};
+
+enum Format {
+    Toml,
+    Json,
+}
+
+impl Format {
+    fn from_path(path: &Path) -> Result<Self, ImGuiError> {
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some(ext) if ext.eq_ignore_ascii_case("toml") => Ok(Format::Toml),
+            Some(ext) if ext.eq_ignore_ascii_case("json") => Ok(Format::Json),
+            _ => Err(theme_error(path, "expected a .toml or .json extension")),
+        }
+    }
+}
+
+fn theme_error(path: &Path, message: impl ToString) -> ImGuiError {
+    ImGuiError::Theme {
+        path: path.to_path_buf(),
+        message: message.to_string(),
+    }
+}
+
+impl Style {
+    /// Read a style from a TOML or JSON theme file.
+    ///
+    /// Values that fail `Style::validate()` are reported as
+    /// `ImGuiError::Theme`, like any other error in the file.
+    pub fn load(path: impl AsRef<Path>) -> Result<Style, ImGuiError> {
+        let path = path.as_ref();
+        let format = Format::from_path(path)?;
+        let text = fs::read_to_string(path).map_err(|e| theme_error(path, e))?;
+        let style: Style = match format {
+            Format::Toml => toml::from_str(&text).map_err(|e| theme_error(path, e))?,
+            Format::Json => serde_json::from_str(&text).map_err(|e| theme_error(path, e))?,
+        };
+        style.validate().map_err(|e| theme_error(path, e))?;
+        Ok(style)
+    }
+
+    /// Write the style to a TOML or JSON theme file.
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), ImGuiError> {
+        let path = path.as_ref();
+        let text = match Format::from_path(path)? {
+            Format::Toml => toml::to_string(self).map_err(|e| theme_error(path, e))?,
+            Format::Json => serde_json::to_string_pretty(self).map_err(|e| theme_error(path, e))?,
+        };
+        fs::write(path, text).map_err(|e| theme_error(path, e))
+    }
+}
+
+impl ImGui {
+    /// Load a theme file and make it the current style.
+    ///
+    /// The current style is left unchanged if the file cannot be loaded.
+    pub fn load_theme(&mut self, path: impl AsRef<Path>) -> Result<(), ImGuiError> {
+        let style = Style::load(path)?;
+        self.set_style(&style)
+    }
+
+    /// Save the current style to a theme file.
+    pub fn save_theme(&self, path: impl AsRef<Path>) -> Result<(), ImGuiError> {
+        self.style().save(path)
+    }
+}
+
+/// Reloads a theme file when it changes on disk.
+///
+/// Call `poll()` once per frame, outside of a frame:
+///
+/// ```ignore
+/// let mut watcher = ThemeWatcher::new("theme.toml");
+/// // in the render loop
+/// if let Err(e) = watcher.poll(&mut imgui) {
+///     eprintln!("{e}");
+/// }
+/// ```
+pub struct ThemeWatcher {
+    path: PathBuf,
+    modified: Option<SystemTime>,
+}
+
+impl ThemeWatcher {
+    /// Watch `path`. The first `poll()` loads the file if it exists.
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self {
+            path: path.into(),
+            modified: None,
+        }
+    }
+
+    /// The watched file.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Apply the theme if the file changed since the last poll.
+    ///
+    /// Returns `Ok(true)` when a new style was applied. A file that fails to
+    /// parse or holds out-of-range values, for example while it is
+    /// half-saved, leaves the current style in place and is retried on its
+    /// next change.
+    pub fn poll(&mut self, imgui: &mut ImGui) -> Result<bool, ImGuiError> {
+        let modified = match fs::metadata(&self.path).and_then(|m| m.modified()) {
+            Ok(modified) => modified,
+            Err(_) => return Ok(false),
+        };
+        if self.modified == Some(modified) {
+            return Ok(false);
+        }
+        self.modified = Some(modified);
+        imgui.load_theme(&self.path)?;
+        Ok(true)
+    }
+}
+
+/// Serializes the color table as a map from `StyleColor` name to RGBA.
+pub(crate) mod colors_by_name {
+    use super::*;
+    use serde::ser::SerializeMap;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    type Colors = [[f32; 4]; StyleColor::COUNT];
+
+    pub fn serialize<S: Serializer>(colors: &Colors, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(Some(StyleColor::COUNT))?;
+        for color in StyleColor::ALL {
+            map.serialize_entry(&color, &colors[color as usize])?;
+        }
+        map.end()
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Colors, D::Error> {
+        let mut colors = Style::default().colors;
+        for (color, value) in HashMap::<StyleColor, [f32; 4]>::deserialize(deserializer)? {
+            colors[color as usize] = value;
+        }
+        Ok(colors)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn light_style_round_trips_through_toml() {
+        let style = Style::light();
+        let text = toml::to_string(&style).unwrap();
+        assert_eq!(toml::from_str::<Style>(&text).unwrap(), style);
+    }
+
+    #[test]
+    fn light_style_round_trips_through_json() {
+        let style = Style::light();
+        let text = serde_json::to_string_pretty(&style).unwrap();
+        assert_eq!(serde_json::from_str::<Style>(&text).unwrap(), style);
+    }
+
+    #[test]
+    fn theme_files_round_trip_by_extension() {
+        let dir = std::env::temp_dir();
+        for name in ["wilhelm_imgui_theme_test.toml", "wilhelm_imgui_theme_test.json"] {
+            let path = dir.join(name);
+            Style::classic().save(&path).unwrap();
+            let loaded = Style::load(&path);
+            let _ = fs::remove_file(&path);
+            assert_eq!(loaded.unwrap(), Style::classic(), "{}", name);
+        }
+        assert!(matches!(Style::classic().save(dir.join("theme.yaml")), Err(ImGuiError::Theme { .. })));
+    }
+
+    #[test]
+    fn partial_files_keep_the_defaults() {
+        let toml = "frame_rounding = 4.0\n\n[colors]\nText = [1.0, 0.0, 0.0, 1.0]\n";
+        let json = r#"{ "frame_rounding": 4.0, "colors": { "Text": [1.0, 0.0, 0.0, 1.0] } }"#;
+        let mut expected = Style {
+            frame_rounding: 4.0,
+            ..Style::default()
+        };
+        expected.colors[StyleColor::Text as usize] = [1.0, 0.0, 0.0, 1.0];
+
+        assert_eq!(toml::from_str::<Style>(toml).unwrap(), expected);
+        assert_eq!(serde_json::from_str::<Style>(json).unwrap(), expected);
+    }
+
+    #[test]
+    fn out_of_range_values_are_rejected_on_load() {
+        let path = std::env::temp_dir().join("wilhelm_imgui_theme_range_test.toml");
+        for text in [
+            "alpha = 1.5\n",
+            "curve_tessellation_tol = 0.0\n",
+            "window_min_size = [0.0, 32.0]\n",
+            "window_menu_button_position = \"Up\"\n",
+            "color_button_position = \"None\"\n",
+        ] {
+            fs::write(&path, text).unwrap();
+            let loaded = Style::load(&path);
+            assert!(matches!(loaded, Err(ImGuiError::Theme { .. })), "{}", text);
+        }
+        let _ = fs::remove_file(&path);
+    }
+
+    #[test]
+    fn unknown_color_names_are_rejected() {
+        assert!(toml::from_str::<Style>("[colors]\nTextColour = [1.0, 0.0, 0.0, 1.0]\n").is_err());
+        assert!(serde_json::from_str::<Style>(r#"{ "colors": { "TextColour": [1.0, 0.0, 0.0, 1.0] } }"#).is_err());
+    }
+}
@@ -0,0 +1,99 @@
+//! Scope guards for paired ImGui calls.
+//!
+//! Every `begin_*`/`push_*` method returns a token that calls the matching
+//! `end_*`/`pop_*` function when dropped, so an unbalanced stack cannot be
+//! produced from safe Rust.
+
+use std::marker::PhantomData;
+
+use crate::{ffi, Ui};
+
+macro_rules! scope_token {
+    ($(#[$meta:meta])* $name:ident => $end:expr) => {
+        $(#[$meta])*
+        #[must_use = "the scope ends as soon as the token is dropped"]
+        pub struct $name<'ui> {
+            _ui: PhantomData<&'ui Ui<'ui>>,
+        }
+
+        impl<'ui> $name<'ui> {
+            pub(crate) fn new(_ui: &'ui Ui<'_>) -> Self {
+                Self { _ui: PhantomData }
+            }
+
+            /// End the scope now. Equivalent to dropping the token.
+            pub fn end(self) {}
+        }
+
+        impl Drop for $name<'_> {
+            fn drop(&mut self) {
+                unsafe { $end }
+            }
+        }
+    };
+}
+
+scope_token!(
+    /// Returned by `begin()`; calls `End()` when dropped.
+    WindowToken => ffi::imgui_end()
+);
+
+scope_token!(
+    /// Returned by `tree_node()`; calls `TreePop()` when dropped.
+    TreeNodeToken => ffi::imgui_tree_pop()
+);
+
+scope_token!(
+    /// Returned by `begin_combo()`; calls `EndCombo()` when dropped.
+    ComboToken => ffi::imgui_end_combo()
+);
+
+scope_token!(
+    /// Returned by `begin_main_menu_bar()`; calls `EndMainMenuBar()` when dropped.
+    MainMenuBarToken => ffi::imgui_end_main_menu_bar()
+);
+
+scope_token!(
+    /// Returned by `begin_menu()`; calls `EndMenu()` when dropped.
+    MenuToken => ffi::imgui_end_menu()
+);
+
+scope_token!(
+    /// Returned by `begin_tooltip()`; calls `EndTooltip()` when dropped.
+    TooltipToken => ffi::imgui_end_tooltip()
+);
+
+scope_token!(
+    /// Returned by `begin_popup()` and `begin_popup_modal()`; calls `EndPopup()` when dropped.
+    PopupToken => ffi::imgui_end_popup()
+);
+
+scope_token!(
+    /// Returned by `begin_table()`; calls `EndTable()` when dropped.
+    TableToken => ffi::imgui_end_table()
+);
+
+scope_token!(
+    /// Returned by `push_id()` and `push_id_int()`; calls `PopID()` when dropped.
+    IdToken => ffi::imgui_pop_id()
+);
+
+scope_token!(
+    /// Returned by `push_style_color()`; pops the color when dropped.
+    StyleColorToken => ffi::imgui_pop_style_color(1)
+);
+
+scope_token!(
+    /// Returned by `push_style_var()`; calls `PopStyleVar()` when dropped.
+    StyleVarToken => ffi::imgui_pop_style_var(1)
+);
+
+scope_token!(
+    /// Returned by `push_font()`; calls `PopFont()` when dropped.
+    FontToken => ffi::imgui_pop_font()
+);
+
+scope_token!(
+    /// Returned by `push_text_wrap_pos()`; calls `PopTextWrapPos()` when dropped.
+    TextWrapPosToken => ffi::imgui_pop_text_wrap_pos()
+);
@@ -0,0 +1,1511 @@
+//! Frame-scoped widget API.
+
+use std::fmt;
+use std::marker::PhantomData;
+use std::ops::RangeInclusive;
+use std::os::raw::{c_int, c_void};
+use std::ptr;
+use std::slice;
+use std::sync::atomic::{AtomicPtr, Ordering};
+
+use wilhelm_renderer::core::Color;
+
+use crate::color::{color_from_rgb, color_to_rgb};
+use crate::data_type::{data_type_id, slider_range, FLOAT, S32};
+use crate::draw_data;
+use crate::format::valid_format;
+use crate::input_text::edit_string;
+use crate::string::{check_nul, str_range};
+use crate::style::StyleVarValue;
+use crate::text::with_formatted;
+use crate::{
+    ffi, AngleSlider, ButtonFlags, ColorEditFlags, ComboFlags, ComboToken, Cond, DataType, Direction, FontId, FontToken,
+    IdToken, ImGui, ImGuiError, InputTextCallbackData, InputTextFlags, MainMenuBarToken, MenuToken, MouseButton,
+    PopupToken, RgbaImage, SelectableFlags, SliderFlags, SoftwareRenderer, StyleColor, StyleColorToken, StyleVar,
+    StyleVarToken, TableColumnFlags, TableFlags, TableToken, TextWrapPosToken, TextureId, TooltipToken, TreeNodeToken,
+    WindowBuilder, WindowFlags, WindowToken,
+};
+
+/// Context whose frame is open. `Ui` methods draw into the current context,
+/// which a second context's `new_frame()` would switch away from.
+static OPEN_FRAME: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+
+/// Mark the frame of `ctx` as open, panicking if another one is.
+pub(crate) fn open_frame(ctx: *mut c_void) {
+    let opened = OPEN_FRAME.compare_exchange(ptr::null_mut(), ctx, Ordering::AcqRel, Ordering::Acquire);
+    assert!(
+        opened.is_ok(),
+        "a frame of another ImGui context is still open; render or drop its `Ui` first"
+    );
+}
+
+fn close_frame() {
+    OPEN_FRAME.store(ptr::null_mut(), Ordering::Release);
+}
+
+/// Handle for the frame currently being built.
+///
+/// Obtained from `ImGui::new_frame()` and consumed by `render()`, so widgets
+/// can only be submitted between the two. Dropping a `Ui` without rendering
+/// ends the frame without drawing it. Only one frame may be open at a time,
+/// across all contexts.
+///
+/// Strings are cut at their first NUL byte, as C would see them. Methods that
+/// commonly display user data have a `try_*` variant that returns
+/// `ImGuiError::InteriorNul` instead.
+///
+/// Printf formats given to numeric widgets must match the value's type; an
+/// invalid format is replaced by ImGui's default for the type. The `Slider`
+/// and `InputScalar` builders report it with `try_build()`.
+pub struct Ui<'frame> {
+    _imgui: PhantomData<&'frame mut ImGui>,
+}
+
+impl<'frame> Ui<'frame> {
+    pub(crate) fn new(_imgui: &'frame mut ImGui) -> Self {
+        Self {
+            _imgui: PhantomData,
+        }
+    }
+
+    /// Finalize and render the ImGui frame. Call this at the end of your render loop.
+    pub fn render(self) {
+        unsafe {
+            ffi::imgui_render();
+            ffi::imgui_opengl3_render_draw_data();
+        }
+        close_frame();
+        std::mem::forget(self);
+    }
+
+    /// Finalize the frame and rasterize it on the CPU instead of with
+    /// OpenGL. The image has the display's framebuffer size and a
+    /// transparent background.
+    pub fn render_software(self, renderer: &SoftwareRenderer) -> RgbaImage {
+        unsafe { ffi::imgui_render() };
+        close_frame();
+        std::mem::forget(self);
+        // Safety: the frame's `ImGui` borrow outlives this call
+        match unsafe { draw_data::draw_data() } {
+            Some(draw_data) => renderer.render(&draw_data),
+            None => RgbaImage::new(0, 0),
+        }
+    }
+
+    // ---- Windows ----
+
+    /// Begin a new window. Returns a token that ends the window when dropped,
+    /// or `None` if the window is collapsed (the window is then already ended).
+    ///
+    /// # Arguments
+    /// * `name` - Window title/ID
+    /// * `open` - Optional mutable bool; if Some, shows a close button
+    /// * `flags` - Window flags
+    pub fn begin(
+        &self,
+        name: &str,
+        open: Option<&mut bool>,
+        flags: WindowFlags,
+    ) -> Option<WindowToken<'_>> {
+        let (name, name_end) = str_range(name);
+        let flags = flags.bits();
+        let visible = unsafe {
+            match open {
+                Some(open_ref) => {
+                    let mut open_int = if *open_ref { 1 } else { 0 };
+                    let result = ffi::imgui_begin(name, name_end, &mut open_int, flags);
+                    *open_ref = open_int != 0;
+                    result != 0
+                }
+                None => ffi::imgui_begin(name, name_end, ptr::null_mut(), flags) != 0,
+            }
+        };
+        // End() must be called regardless of what Begin() returned
+        let token = WindowToken::new(self);
+        visible.then_some(token)
+    }
+
+    /// Create a window builder for the closure-based API.
+    pub fn window<'a>(&self, name: &'a str) -> WindowBuilder<'_, 'a> {
+        WindowBuilder::new(self, name)
+    }
+
+    /// Set position of the next window.
+    pub fn set_next_window_pos(&self, x: f32, y: f32, cond: Cond) {
+        unsafe { ffi::imgui_set_next_window_pos(x, y, cond as i32) };
+    }
+
+    /// Set size of the next window.
+    pub fn set_next_window_size(&self, width: f32, height: f32, cond: Cond) {
+        unsafe { ffi::imgui_set_next_window_size(width, height, cond as i32) };
+    }
+
+    // ---- Widgets: Text ----
+
+    /// Display text.
+    pub fn text(&self, text: &str) {
+        let (text, text_end) = str_range(text);
+        unsafe { ffi::imgui_text(text, text_end) };
+    }
+
+    /// Display text, failing if it contains a NUL byte.
+    pub fn try_text(&self, text: &str) -> Result<(), ImGuiError> {
+        check_nul(text)?;
+        self.text(text);
+        Ok(())
+    }
+
+    /// Display formatted text through a reusable buffer. See the `text!` macro.
+    pub fn text_fmt(&self, args: fmt::Arguments) {
+        with_formatted(args, |text| self.text(text));
+    }
+
+    /// Display text in the given RGBA color.
+    pub fn text_colored(&self, color: [f32; 4], text: &str) {
+        let [r, g, b, a] = color;
+        let (text, text_end) = str_range(text);
+        unsafe { ffi::imgui_text_colored(r, g, b, a, text, text_end) };
+    }
+
+    /// Display text in the style's `TextDisabled` color.
+    pub fn text_disabled(&self, text: &str) {
+        let (text, text_end) = str_range(text);
+        unsafe { ffi::imgui_text_disabled(text, text_end) };
+    }
+
+    /// Display text wrapped at the window edge, or at the position set with
+    /// `push_text_wrap_pos()`.
+    pub fn text_wrapped(&self, text: &str) {
+        let (text, text_end) = str_range(text);
+        unsafe { ffi::imgui_text_wrapped(text, text_end) };
+    }
+
+    /// Display a value and a label aligned like the other labeled widgets.
+    pub fn label_text(&self, label: &str, text: &str) {
+        let (label, label_end) = str_range(label);
+        let (text, text_end) = str_range(text);
+        unsafe { ffi::imgui_label_text(label, label_end, text, text_end) };
+    }
+
+    /// Horizontal separator with a title.
+    pub fn separator_text(&self, label: &str) {
+        let (label, label_end) = str_range(label);
+        unsafe { ffi::imgui_separator_text(label, label_end) };
+    }
+
+    /// Wrap text at `wrap_local_pos_x`, in window-local coordinates, until the
+    /// returned token is dropped. 0.0 wraps at the window edge and a negative
+    /// value disables wrapping.
+    pub fn push_text_wrap_pos(&self, wrap_local_pos_x: f32) -> TextWrapPosToken<'_> {
+        unsafe { ffi::imgui_push_text_wrap_pos(wrap_local_pos_x) };
+        TextWrapPosToken::new(self)
+    }
+
+    /// Run `f` with text wrapped at `wrap_local_pos_x`.
+    pub fn with_text_wrap_pos<R, F: FnOnce() -> R>(&self, wrap_local_pos_x: f32, f: F) -> R {
+        let _token = self.push_text_wrap_pos(wrap_local_pos_x);
+        f()
+    }
+
+    /// Size of `text` in the current font, without wrapping.
+    pub fn calc_text_size(&self, text: &str) -> [f32; 2] {
+        let (text, text_end) = str_range(text);
+        let mut size = [0.0; 2];
+        unsafe { ffi::imgui_calc_text_size(text, text_end, size.as_mut_ptr()) };
+        size
+    }
+
+    // ---- Widgets: Buttons ----
+
+    /// Button widget. Returns true if clicked.
+    pub fn button(&self, label: &str) -> bool {
+        let (label, label_end) = str_range(label);
+        unsafe { ffi::imgui_button(label, label_end) != 0 }
+    }
+
+    /// Button widget, failing if the label contains a NUL byte.
+    pub fn try_button(&self, label: &str) -> Result<bool, ImGuiError> {
+        check_nul(label)?;
+        Ok(self.button(label))
+    }
+
+    /// Button without vertical frame padding, to fit within text. Returns true if clicked.
+    pub fn small_button(&self, label: &str) -> bool {
+        let (label, label_end) = str_range(label);
+        unsafe { ffi::imgui_small_button(label, label_end) != 0 }
+    }
+
+    /// Square button showing an arrow. Returns true if clicked.
+    pub fn arrow_button(&self, str_id: &str, dir: Direction) -> bool {
+        let (str_id, str_id_end) = str_range(str_id);
+        unsafe { ffi::imgui_arrow_button(str_id, str_id_end, dir as c_int) != 0 }
+    }
+
+    /// Button with no visuals, for custom widgets built with the item
+    /// queries and draw calls. Returns true if clicked with one of the
+    /// buttons in `flags` (the left button by default).
+    pub fn invisible_button(&self, str_id: &str, size: [f32; 2], flags: ButtonFlags) -> bool {
+        let (str_id, str_id_end) = str_range(str_id);
+        unsafe { ffi::imgui_invisible_button(str_id, str_id_end, size[0], size[1], flags.bits()) != 0 }
+    }
+
+    /// Radio button shown selected when `active`. Returns true if clicked.
+    pub fn radio_button(&self, label: &str, active: bool) -> bool {
+        let (label, label_end) = str_range(label);
+        unsafe { ffi::imgui_radio_button(label, label_end, active as c_int) != 0 }
+    }
+
+    /// Radio button selecting `value` for `v`, e.g. an enum variant.
+    /// Returns true if clicked.
+    ///
+    /// ```ignore
+    /// ui.radio_button_value("Fill", &mut mode, Mode::Fill);
+    /// ui.same_line();
+    /// ui.radio_button_value("Stroke", &mut mode, Mode::Stroke);
+    /// ```
+    pub fn radio_button_value<T: PartialEq>(&self, label: &str, v: &mut T, value: T) -> bool {
+        let clicked = self.radio_button(label, *v == value);
+        if clicked {
+            *v = value;
+        }
+        clicked
+    }
+
+    /// Progress bar filled to `fraction` (0..1), showing `overlay` or the
+    /// percentage. A width of `-f32::MIN_POSITIVE` spans the available
+    /// width and a height of 0 uses the frame height.
+    pub fn progress_bar(&self, fraction: f32, size: [f32; 2], overlay: Option<&str>) {
+        let (overlay, overlay_end) = overlay.map_or((ptr::null(), ptr::null()), str_range);
+        unsafe { ffi::imgui_progress_bar(fraction, size[0], size[1], overlay, overlay_end) };
+    }
+
+    /// Draw a bullet point and keep the cursor on the same line.
+    pub fn bullet(&self) {
+        unsafe { ffi::imgui_bullet() };
+    }
+
+    /// Display text after a bullet point.
+    pub fn bullet_text(&self, text: &str) {
+        let (text, text_end) = str_range(text);
+        unsafe { ffi::imgui_bullet_text(text, text_end) };
+    }
+
+    /// Display text after a bullet point, failing if it contains a NUL byte.
+    pub fn try_bullet_text(&self, text: &str) -> Result<(), ImGuiError> {
+        check_nul(text)?;
+        self.bullet_text(text);
+        Ok(())
+    }
+
+    /// Hyperlink-style text button. Returns true if clicked.
+    pub fn text_link(&self, label: &str) -> bool {
+        let (label, label_end) = str_range(label);
+        unsafe { ffi::imgui_text_link(label, label_end) != 0 }
+    }
+
+    /// Hyperlink that opens `url` in the system browser when clicked.
+    /// Right-clicking offers to copy the URL.
+    pub fn text_link_open_url(&self, label: &str, url: &str) {
+        let (label, label_end) = str_range(label);
+        let (url, url_end) = str_range(url);
+        unsafe { ffi::imgui_text_link_open_url(label, label_end, url, url_end) };
+    }
+
+    /// Checkbox widget. Returns true if value changed.
+    pub fn checkbox(&self, label: &str, v: &mut bool) -> bool {
+        let (label, label_end) = str_range(label);
+        let mut v_int = if *v { 1 } else { 0 };
+        let changed = unsafe { ffi::imgui_checkbox(label, label_end, &mut v_int) != 0 };
+        *v = v_int != 0;
+        changed
+    }
+
+    // ---- Widgets: Sliders ----
+
+    /// Float slider. Returns true if value changed.
+    ///
+    /// `min` and `max` are clamped to half of `f32`'s limits.
+    pub fn slider_float(&self, label: &str, v: &mut f32, min: f32, max: f32) -> bool {
+        let (label, label_end) = str_range(label);
+        let (min, max) = slider_range(min..=max).into_inner();
+        unsafe { ffi::imgui_slider_float(label, label_end, v, min, max) != 0 }
+    }
+
+    /// Int slider. Returns true if value changed.
+    ///
+    /// `min` and `max` are clamped to half of `i32`'s limits.
+    pub fn slider_int(&self, label: &str, v: &mut i32, min: i32, max: i32) -> bool {
+        let (label, label_end) = str_range(label);
+        let (min, max) = slider_range(min..=max).into_inner();
+        unsafe { ffi::imgui_slider_int(label, label_end, v, min, max) != 0 }
+    }
+
+    /// Two-component float slider. Returns true if any value changed.
+    pub fn slider_float2(&self, label: &str, v: &mut [f32; 2], min: f32, max: f32) -> bool {
+        self.slider_scalar_n(label, v, min..=max, None, SliderFlags::empty())
+    }
+
+    /// Three-component float slider. Returns true if any value changed.
+    pub fn slider_float3(&self, label: &str, v: &mut [f32; 3], min: f32, max: f32) -> bool {
+        self.slider_scalar_n(label, v, min..=max, None, SliderFlags::empty())
+    }
+
+    /// Four-component float slider. Returns true if any value changed.
+    pub fn slider_float4(&self, label: &str, v: &mut [f32; 4], min: f32, max: f32) -> bool {
+        self.slider_scalar_n(label, v, min..=max, None, SliderFlags::empty())
+    }
+
+    /// Slider for any numeric type. Returns true if value changed.
+    ///
+    /// `format` defaults to `"%d"` for integers and `"%.3f"` for floats.
+    /// ImGui only supports half of the type's limits for 32-bit and wider
+    /// types, so e.g. `0..=u64::MAX` slides over `0..=u64::MAX / 2`; NaN
+    /// bounds are replaced by those limits. `SliderFlags::WRAP_AROUND` only
+    /// applies to drags and is ignored. `Slider::try_build` reports either
+    /// instead.
+    pub fn slider_scalar<T: DataType>(
+        &self,
+        label: &str,
+        v: &mut T,
+        range: RangeInclusive<T>,
+        format: Option<&str>,
+        flags: SliderFlags,
+    ) -> bool {
+        self.slider_scalar_n(label, slice::from_mut(v), range, format, flags)
+    }
+
+    /// Slider editing each element of `v` side by side. Returns true if any
+    /// value changed.
+    ///
+    /// `range` is clamped as in `slider_scalar()`, and
+    /// `SliderFlags::WRAP_AROUND` is ignored.
+    pub fn slider_scalar_n<T: DataType>(
+        &self,
+        label: &str,
+        v: &mut [T],
+        range: RangeInclusive<T>,
+        format: Option<&str>,
+        flags: SliderFlags,
+    ) -> bool {
+        if v.is_empty() {
+            return false;
+        }
+        let (label, label_end) = str_range(label);
+        let format = valid_format(format, data_type_id::<T>());
+        let (format, format_end) = format.map_or((ptr::null(), ptr::null()), str_range);
+        let range = slider_range(range);
+        unsafe {
+            ffi::imgui_slider_scalar_n(
+                label,
+                label_end,
+                data_type_id::<T>(),
+                v.as_mut_ptr() as *mut c_void,
+                v.len() as c_int,
+                range.start() as *const T as *const c_void,
+                range.end() as *const T as *const c_void,
+                format,
+                format_end,
+                (flags - SliderFlags::WRAP_AROUND).bits(),
+            ) != 0
+        }
+    }
+
+    /// Vertical float slider of the given size. Returns true if value changed.
+    pub fn v_slider_float(&self, label: &str, size: [f32; 2], v: &mut f32, min: f32, max: f32) -> bool {
+        self.v_slider_scalar(label, size, v, min..=max, None, SliderFlags::empty())
+    }
+
+    /// Vertical int slider of the given size. Returns true if value changed.
+    pub fn v_slider_int(&self, label: &str, size: [f32; 2], v: &mut i32, min: i32, max: i32) -> bool {
+        self.v_slider_scalar(label, size, v, min..=max, None, SliderFlags::empty())
+    }
+
+    /// Vertical slider for any numeric type. Returns true if value changed.
+    ///
+    /// `range` is clamped as in `slider_scalar()`, and
+    /// `SliderFlags::WRAP_AROUND` is ignored.
+    pub fn v_slider_scalar<T: DataType>(
+        &self,
+        label: &str,
+        size: [f32; 2],
+        v: &mut T,
+        range: RangeInclusive<T>,
+        format: Option<&str>,
+        flags: SliderFlags,
+    ) -> bool {
+        let (label, label_end) = str_range(label);
+        let format = valid_format(format, data_type_id::<T>());
+        let (format, format_end) = format.map_or((ptr::null(), ptr::null()), str_range);
+        let range = slider_range(range);
+        unsafe {
+            ffi::imgui_v_slider_scalar(
+                label,
+                label_end,
+                size[0],
+                size[1],
+                data_type_id::<T>(),
+                v as *mut T as *mut c_void,
+                range.start() as *const T as *const c_void,
+                range.end() as *const T as *const c_void,
+                format,
+                format_end,
+                (flags - SliderFlags::WRAP_AROUND).bits(),
+            ) != 0
+        }
+    }
+
+    /// Angle slider editing `v_rad` in radians, shown in degrees from -360
+    /// to 360. Returns true if value changed.
+    pub fn slider_angle(&self, label: &str, v_rad: &mut f32) -> bool {
+        AngleSlider::new(label).build(self, v_rad)
+    }
+
+    // ---- Widgets: Drags ----
+
+    /// Float drag. Dragging by one pixel changes the value by `speed`.
+    /// Returns true if value changed.
+    ///
+    /// Values are kept within `range` unless it is empty, e.g. `0.0..=0.0`.
+    /// `format` is a printf format such as `"%.3f"`.
+    pub fn drag_float(
+        &self,
+        label: &str,
+        v: &mut f32,
+        speed: f32,
+        range: RangeInclusive<f32>,
+        format: &str,
+        flags: SliderFlags,
+    ) -> bool {
+        let (label, label_end) = str_range(label);
+        let format = valid_format(Some(format), FLOAT);
+        let (format, format_end) = format.map_or((ptr::null(), ptr::null()), str_range);
+        unsafe {
+            ffi::imgui_drag_float(
+                label, label_end, v, speed, *range.start(), *range.end(), format, format_end, flags.bits(),
+            ) != 0
+        }
+    }
+
+    /// Int drag. Returns true if value changed.
+    ///
+    /// Values are kept within `range` unless it is empty, e.g. `0..=0`.
+    /// `format` is a printf format such as `"%d"`.
+    pub fn drag_int(
+        &self,
+        label: &str,
+        v: &mut i32,
+        speed: f32,
+        range: RangeInclusive<i32>,
+        format: &str,
+        flags: SliderFlags,
+    ) -> bool {
+        let (label, label_end) = str_range(label);
+        let format = valid_format(Some(format), S32);
+        let (format, format_end) = format.map_or((ptr::null(), ptr::null()), str_range);
+        unsafe {
+            ffi::imgui_drag_int(
+                label, label_end, v, speed, *range.start(), *range.end(), format, format_end, flags.bits(),
+            ) != 0
+        }
+    }
+
+    /// Drag editing the two ends of a float range, keeping `min <= max`.
+    /// Returns true if either value changed.
+    ///
+    /// `format_max` formats the upper end; `None` uses `format` for both.
+    #[allow(clippy::too_many_arguments)]
+    pub fn drag_float_range2(
+        &self,
+        label: &str,
+        min: &mut f32,
+        max: &mut f32,
+        speed: f32,
+        range: RangeInclusive<f32>,
+        format: &str,
+        format_max: Option<&str>,
+        flags: SliderFlags,
+    ) -> bool {
+        let (label, label_end) = str_range(label);
+        let format = valid_format(Some(format), FLOAT);
+        let (format, format_end) = format.map_or((ptr::null(), ptr::null()), str_range);
+        let format_max = valid_format(format_max, FLOAT);
+        let (format_max, format_max_end) = format_max.map_or((ptr::null(), ptr::null()), str_range);
+        unsafe {
+            ffi::imgui_drag_float_range2(
+                label,
+                label_end,
+                min,
+                max,
+                speed,
+                *range.start(),
+                *range.end(),
+                format,
+                format_end,
+                format_max,
+                format_max_end,
+                flags.bits(),
+            ) != 0
+        }
+    }
+
+    /// Drag editing the two ends of an int range, keeping `min <= max`.
+    /// Returns true if either value changed.
+    #[allow(clippy::too_many_arguments)]
+    pub fn drag_int_range2(
+        &self,
+        label: &str,
+        min: &mut i32,
+        max: &mut i32,
+        speed: f32,
+        range: RangeInclusive<i32>,
+        format: &str,
+        format_max: Option<&str>,
+        flags: SliderFlags,
+    ) -> bool {
+        let (label, label_end) = str_range(label);
+        let format = valid_format(Some(format), S32);
+        let (format, format_end) = format.map_or((ptr::null(), ptr::null()), str_range);
+        let format_max = valid_format(format_max, S32);
+        let (format_max, format_max_end) = format_max.map_or((ptr::null(), ptr::null()), str_range);
+        unsafe {
+            ffi::imgui_drag_int_range2(
+                label,
+                label_end,
+                min,
+                max,
+                speed,
+                *range.start(),
+                *range.end(),
+                format,
+                format_end,
+                format_max,
+                format_max_end,
+                flags.bits(),
+            ) != 0
+        }
+    }
+
+    /// Two-component float drag. Returns true if any value changed.
+    pub fn drag_float2(
+        &self,
+        label: &str,
+        v: &mut [f32; 2],
+        speed: f32,
+        range: RangeInclusive<f32>,
+        format: &str,
+        flags: SliderFlags,
+    ) -> bool {
+        self.drag_scalar_n(label, v, speed, range, Some(format), flags)
+    }
+
+    /// Three-component float drag. Returns true if any value changed.
+    pub fn drag_float3(
+        &self,
+        label: &str,
+        v: &mut [f32; 3],
+        speed: f32,
+        range: RangeInclusive<f32>,
+        format: &str,
+        flags: SliderFlags,
+    ) -> bool {
+        self.drag_scalar_n(label, v, speed, range, Some(format), flags)
+    }
+
+    /// Four-component float drag. Returns true if any value changed.
+    pub fn drag_float4(
+        &self,
+        label: &str,
+        v: &mut [f32; 4],
+        speed: f32,
+        range: RangeInclusive<f32>,
+        format: &str,
+        flags: SliderFlags,
+    ) -> bool {
+        self.drag_scalar_n(label, v, speed, range, Some(format), flags)
+    }
+
+    /// Drag for any numeric type. Returns true if value changed.
+    ///
+    /// Values are kept within `range` unless it is empty. `format` defaults
+    /// to `"%d"` for integers and `"%.3f"` for floats.
+    pub fn drag_scalar<T: DataType>(
+        &self,
+        label: &str,
+        v: &mut T,
+        speed: f32,
+        range: RangeInclusive<T>,
+        format: Option<&str>,
+        flags: SliderFlags,
+    ) -> bool {
+        self.drag_scalar_n(label, slice::from_mut(v), speed, range, format, flags)
+    }
+
+    /// Drag editing each element of `v` side by side. Returns true if any
+    /// value changed.
+    pub fn drag_scalar_n<T: DataType>(
+        &self,
+        label: &str,
+        v: &mut [T],
+        speed: f32,
+        range: RangeInclusive<T>,
+        format: Option<&str>,
+        flags: SliderFlags,
+    ) -> bool {
+        if v.is_empty() {
+            return false;
+        }
+        let (label, label_end) = str_range(label);
+        let format = valid_format(format, data_type_id::<T>());
+        let (format, format_end) = format.map_or((ptr::null(), ptr::null()), str_range);
+        unsafe {
+            ffi::imgui_drag_scalar_n(
+                label,
+                label_end,
+                data_type_id::<T>(),
+                v.as_mut_ptr() as *mut c_void,
+                v.len() as c_int,
+                speed,
+                range.start() as *const T as *const c_void,
+                range.end() as *const T as *const c_void,
+                format,
+                format_end,
+                flags.bits(),
+            ) != 0
+        }
+    }
+
+    // ---- Widgets: Input ----
+
+    /// Float input. Returns true if value changed.
+    pub fn input_float(&self, label: &str, v: &mut f32) -> bool {
+        let (label, label_end) = str_range(label);
+        unsafe { ffi::imgui_input_float(label, label_end, v) != 0 }
+    }
+
+    /// Int input. Returns true if value changed.
+    pub fn input_int(&self, label: &str, v: &mut i32) -> bool {
+        let (label, label_end) = str_range(label);
+        unsafe { ffi::imgui_input_int(label, label_end, v) != 0 }
+    }
+
+    /// Two-component float input. Returns true if any value changed.
+    pub fn input_float2(&self, label: &str, v: &mut [f32; 2]) -> bool {
+        self.input_scalar_n(label, v, None, None, None, InputTextFlags::empty())
+    }
+
+    /// Three-component float input. Returns true if any value changed.
+    pub fn input_float3(&self, label: &str, v: &mut [f32; 3]) -> bool {
+        self.input_scalar_n(label, v, None, None, None, InputTextFlags::empty())
+    }
+
+    /// Four-component float input. Returns true if any value changed.
+    pub fn input_float4(&self, label: &str, v: &mut [f32; 4]) -> bool {
+        self.input_scalar_n(label, v, None, None, None, InputTextFlags::empty())
+    }
+
+    /// Input for any numeric type. Returns true if value changed.
+    ///
+    /// With a `step`, +/- buttons are shown; `step_fast` applies while Ctrl
+    /// is held. `format` defaults to `"%d"` for integers and `"%.3f"` for
+    /// floats.
+    pub fn input_scalar<T: DataType>(
+        &self,
+        label: &str,
+        v: &mut T,
+        step: Option<T>,
+        step_fast: Option<T>,
+        format: Option<&str>,
+        flags: InputTextFlags,
+    ) -> bool {
+        self.input_scalar_n(label, slice::from_mut(v), step, step_fast, format, flags)
+    }
+
+    /// Input editing each element of `v` side by side. Returns true if any
+    /// value changed.
+    pub fn input_scalar_n<T: DataType>(
+        &self,
+        label: &str,
+        v: &mut [T],
+        step: Option<T>,
+        step_fast: Option<T>,
+        format: Option<&str>,
+        flags: InputTextFlags,
+    ) -> bool {
+        if v.is_empty() {
+            return false;
+        }
+        let (label, label_end) = str_range(label);
+        let format = valid_format(format, data_type_id::<T>());
+        let (format, format_end) = format.map_or((ptr::null(), ptr::null()), str_range);
+        let step = step.as_ref().map_or(ptr::null(), |step| step as *const T as *const c_void);
+        let step_fast = step_fast.as_ref().map_or(ptr::null(), |step| step as *const T as *const c_void);
+        unsafe {
+            ffi::imgui_input_scalar_n(
+                label,
+                label_end,
+                data_type_id::<T>(),
+                v.as_mut_ptr() as *mut c_void,
+                v.len() as c_int,
+                step,
+                step_fast,
+                format,
+                format_end,
+                flags.bits(),
+            ) != 0
+        }
+    }
+
+    /// Single-line text input editing `text`. Returns true if the text
+    /// changed, or when Enter is pressed with `ENTER_RETURNS_TRUE`.
+    ///
+    /// The string grows as needed. Text after a NUL byte is dropped.
+    pub fn input_text(&self, label: &str, text: &mut String, flags: InputTextFlags) -> bool {
+        let (label, label_end) = str_range(label);
+        edit_string(text, flags, None, |buf, size, flags, callback, user_data| unsafe {
+            ffi::imgui_input_text(label, label_end, buf, size, flags, callback, user_data) != 0
+        })
+    }
+
+    /// Single-line text input that calls `callback` for the events enabled
+    /// in `flags`: `CALLBACK_COMPLETION`, `CALLBACK_HISTORY`,
+    /// `CALLBACK_CHAR_FILTER`, `CALLBACK_EDIT` and `CALLBACK_ALWAYS`.
+    ///
+    /// ```ignore
+    /// ui.input_text_with_callback("Command", &mut line, InputTextFlags::CALLBACK_HISTORY, |data| {
+    ///     if let InputTextEvent::History(dir) = data.event() {
+    ///         data.set_text(history.step(dir));
+    ///     }
+    /// });
+    /// ```
+    pub fn input_text_with_callback<F>(
+        &self,
+        label: &str,
+        text: &mut String,
+        flags: InputTextFlags,
+        mut callback: F,
+    ) -> bool
+    where
+        F: FnMut(&mut InputTextCallbackData),
+    {
+        let (label, label_end) = str_range(label);
+        edit_string(text, flags, Some(&mut callback), |buf, size, flags, callback, user_data| unsafe {
+            ffi::imgui_input_text(label, label_end, buf, size, flags, callback, user_data) != 0
+        })
+    }
+
+    /// Multi-line text input editing `text`. A size of `[0.0, 0.0]` uses the default size.
+    ///
+    /// `ELIDE_LEFT` and `CALLBACK_HISTORY`, which ImGui does not support on
+    /// multi-line inputs, are ignored.
+    pub fn input_text_multiline(
+        &self,
+        label: &str,
+        text: &mut String,
+        size: [f32; 2],
+        flags: InputTextFlags,
+    ) -> bool {
+        let (label, label_end) = str_range(label);
+        let flags = flags - (InputTextFlags::ELIDE_LEFT | InputTextFlags::CALLBACK_HISTORY);
+        edit_string(text, flags, None, |buf, buf_size, flags, callback, user_data| unsafe {
+            ffi::imgui_input_text_multiline(
+                label, label_end, buf, buf_size, size[0], size[1], flags, callback, user_data,
+            ) != 0
+        })
+    }
+
+    /// Single-line text input showing `hint` while `text` is empty.
+    pub fn input_text_with_hint(
+        &self,
+        label: &str,
+        hint: &str,
+        text: &mut String,
+        flags: InputTextFlags,
+    ) -> bool {
+        let (label, label_end) = str_range(label);
+        let (hint, hint_end) = str_range(hint);
+        edit_string(text, flags, None, |buf, size, flags, callback, user_data| unsafe {
+            ffi::imgui_input_text_with_hint(
+                label, label_end, hint, hint_end, buf, size, flags, callback, user_data,
+            ) != 0
+        })
+    }
+
+    // ---- Widgets: Images ----
+
+    /// Display a texture. `uv0` and `uv1` select the texture area, usually
+    /// `[0.0, 0.0]` and `[1.0, 1.0]`; `tint` multiplies the texture color and
+    /// a non-transparent `border` draws a frame around it.
+    pub fn image(
+        &self,
+        texture: TextureId,
+        size: [f32; 2],
+        uv0: [f32; 2],
+        uv1: [f32; 2],
+        tint: [f32; 4],
+        border: [f32; 4],
+    ) {
+        unsafe {
+            ffi::imgui_image(
+                texture.id(),
+                size.as_ptr(),
+                uv0.as_ptr(),
+                uv1.as_ptr(),
+                tint.as_ptr(),
+                border.as_ptr(),
+            )
+        };
+    }
+
+    /// Button showing a texture, framed like `button()`. Returns true if
+    /// clicked. `background` is drawn behind the texture.
+    #[allow(clippy::too_many_arguments)]
+    pub fn image_button(
+        &self,
+        str_id: &str,
+        texture: TextureId,
+        size: [f32; 2],
+        uv0: [f32; 2],
+        uv1: [f32; 2],
+        background: [f32; 4],
+        tint: [f32; 4],
+    ) -> bool {
+        let (str_id, str_id_end) = str_range(str_id);
+        unsafe {
+            ffi::imgui_image_button(
+                str_id,
+                str_id_end,
+                texture.id(),
+                size.as_ptr(),
+                uv0.as_ptr(),
+                uv1.as_ptr(),
+                background.as_ptr(),
+                tint.as_ptr(),
+            ) != 0
+        }
+    }
+
+    // ---- Widgets: Color ----
+
+    /// RGB color editor. Returns true if value changed.
+    pub fn color_edit3(&self, label: &str, col: &mut [f32; 3]) -> bool {
+        self.color_edit3_with_flags(label, col, ColorEditFlags::empty())
+    }
+
+    /// RGBA color editor. Returns true if value changed.
+    pub fn color_edit4(&self, label: &str, col: &mut [f32; 4]) -> bool {
+        self.color_edit4_with_flags(label, col, ColorEditFlags::empty())
+    }
+
+    /// RGB color editor with flags. Returns true if value changed.
+    pub fn color_edit3_with_flags(&self, label: &str, col: &mut [f32; 3], flags: ColorEditFlags) -> bool {
+        let (label, label_end) = str_range(label);
+        unsafe { ffi::imgui_color_edit3(label, label_end, col.as_mut_ptr(), flags.sanitized().bits()) != 0 }
+    }
+
+    /// RGBA color editor with flags. Returns true if value changed.
+    pub fn color_edit4_with_flags(&self, label: &str, col: &mut [f32; 4], flags: ColorEditFlags) -> bool {
+        let (label, label_end) = str_range(label);
+        unsafe { ffi::imgui_color_edit4(label, label_end, col.as_mut_ptr(), flags.sanitized().bits()) != 0 }
+    }
+
+    /// RGB color picker. Returns true if value changed.
+    pub fn color_picker3(&self, label: &str, col: &mut [f32; 3], flags: ColorEditFlags) -> bool {
+        let (label, label_end) = str_range(label);
+        unsafe { ffi::imgui_color_picker3(label, label_end, col.as_mut_ptr(), flags.sanitized().bits()) != 0 }
+    }
+
+    /// RGBA color picker. Returns true if value changed.
+    ///
+    /// With a `reference` color, the side preview shows it next to the
+    /// current color for comparison.
+    pub fn color_picker4(
+        &self,
+        label: &str,
+        col: &mut [f32; 4],
+        flags: ColorEditFlags,
+        reference: Option<&[f32; 4]>,
+    ) -> bool {
+        let (label, label_end) = str_range(label);
+        let reference = reference.map_or(ptr::null(), |reference| reference.as_ptr());
+        unsafe { ffi::imgui_color_picker4(label, label_end, col.as_mut_ptr(), flags.sanitized().bits(), reference) != 0 }
+    }
+
+    /// Color square showing `col`. Returns true when clicked.
+    ///
+    /// `desc_id` is shown in the tooltip. A size of `[0.0, 0.0]` uses the
+    /// frame height.
+    pub fn color_button(&self, desc_id: &str, col: [f32; 4], flags: ColorEditFlags, size: [f32; 2]) -> bool {
+        let (desc_id, desc_id_end) = str_range(desc_id);
+        unsafe {
+            ffi::imgui_color_button(desc_id, desc_id_end, col.as_ptr(), flags.sanitized().bits(), size[0], size[1]) != 0
+        }
+    }
+
+    /// Set the default display, input and picker options of the color
+    /// widgets, which users can otherwise change from the options menu.
+    /// Call once, e.g. after `ImGui::new()` in the first frame.
+    ///
+    /// Groups with more than one flag set keep ImGui's default, as described
+    /// on `ColorEditFlags`.
+    pub fn set_color_edit_options(&self, flags: ColorEditFlags) {
+        unsafe { ffi::imgui_set_color_edit_options(flags.sanitized().bits()) };
+    }
+
+    /// Color editor for a renderer `Color`. Returns true if value changed.
+    ///
+    /// Only RGB is edited since `Color` has no alpha constructor.
+    pub fn color_edit(&self, label: &str, color: &mut Color, flags: ColorEditFlags) -> bool {
+        let mut rgb = color_to_rgb(*color);
+        let changed = self.color_edit3_with_flags(label, &mut rgb, flags);
+        if changed {
+            *color = color_from_rgb(rgb);
+        }
+        changed
+    }
+
+    /// Color picker for a renderer `Color`. Returns true if value changed.
+    pub fn color_picker(&self, label: &str, color: &mut Color, flags: ColorEditFlags) -> bool {
+        let mut rgb = color_to_rgb(*color);
+        let changed = self.color_picker3(label, &mut rgb, flags);
+        if changed {
+            *color = color_from_rgb(rgb);
+        }
+        changed
+    }
+
+    // ---- Layout ----
+
+    /// Place next widget on the same line as the previous one.
+    pub fn same_line(&self) {
+        unsafe { ffi::imgui_same_line() };
+    }
+
+    /// Add a horizontal separator.
+    pub fn separator(&self) {
+        unsafe { ffi::imgui_separator() };
+    }
+
+    /// Add vertical spacing.
+    pub fn spacing(&self) {
+        unsafe { ffi::imgui_spacing() };
+    }
+
+    /// Add a dummy item of given size.
+    pub fn dummy(&self, width: f32, height: f32) {
+        unsafe { ffi::imgui_dummy(width, height) };
+    }
+
+    /// Indent content.
+    pub fn indent(&self, indent_w: f32) {
+        unsafe { ffi::imgui_indent(indent_w) };
+    }
+
+    /// Unindent content.
+    pub fn unindent(&self, indent_w: f32) {
+        unsafe { ffi::imgui_unindent(indent_w) };
+    }
+
+    // ---- Tree ----
+
+    /// Begin a tree node. Returns a token that pops the node when dropped,
+    /// or `None` if the node is closed.
+    pub fn tree_node(&self, label: &str) -> Option<TreeNodeToken<'_>> {
+        let (label, label_end) = str_range(label);
+        let open = unsafe { ffi::imgui_tree_node(label, label_end) != 0 };
+        open.then(|| TreeNodeToken::new(self))
+    }
+
+    /// Begin a tree node, failing if the label contains a NUL byte.
+    pub fn try_tree_node(&self, label: &str) -> Result<Option<TreeNodeToken<'_>>, ImGuiError> {
+        check_nul(label)?;
+        Ok(self.tree_node(label))
+    }
+
+    /// Run `f` inside a tree node if it is open.
+    pub fn tree<R, F: FnOnce() -> R>(&self, label: &str, f: F) -> Option<R> {
+        self.tree_node(label).map(|_token| f())
+    }
+
+    // ---- Combo ----
+
+    /// Begin a combo box. Returns a token that ends the combo when dropped,
+    /// or `None` if the combo is closed.
+    pub fn begin_combo(
+        &self,
+        label: &str,
+        preview: &str,
+        flags: ComboFlags,
+    ) -> Option<ComboToken<'_>> {
+        let (label, label_end) = str_range(label);
+        let (preview, preview_end) = str_range(preview);
+        let flags = flags.bits();
+        let open =
+            unsafe { ffi::imgui_begin_combo(label, label_end, preview, preview_end, flags) != 0 };
+        open.then(|| ComboToken::new(self))
+    }
+
+    /// Run `f` inside a combo box if it is open.
+    pub fn combo<R, F: FnOnce() -> R>(
+        &self,
+        label: &str,
+        preview: &str,
+        flags: ComboFlags,
+        f: F,
+    ) -> Option<R> {
+        self.begin_combo(label, preview, flags).map(|_token| f())
+    }
+
+    /// Selectable item in a combo/list. Returns true if clicked.
+    pub fn selectable(&self, label: &str, selected: bool, flags: SelectableFlags) -> bool {
+        let (label, label_end) = str_range(label);
+        let flags = flags.bits();
+        unsafe { ffi::imgui_selectable(label, label_end, if selected { 1 } else { 0 }, flags) != 0 }
+    }
+
+    /// Selectable item, failing if the label contains a NUL byte.
+    pub fn try_selectable(
+        &self,
+        label: &str,
+        selected: bool,
+        flags: SelectableFlags,
+    ) -> Result<bool, ImGuiError> {
+        check_nul(label)?;
+        Ok(self.selectable(label, selected, flags))
+    }
+
+    // ---- Menu ----
+
+    /// Begin the main menu bar. Returns `None` if the bar is not visible.
+    pub fn begin_main_menu_bar(&self) -> Option<MainMenuBarToken<'_>> {
+        let open = unsafe { ffi::imgui_begin_main_menu_bar() != 0 };
+        open.then(|| MainMenuBarToken::new(self))
+    }
+
+    /// Run `f` inside the main menu bar if it is visible.
+    pub fn main_menu_bar<R, F: FnOnce() -> R>(&self, f: F) -> Option<R> {
+        self.begin_main_menu_bar().map(|_token| f())
+    }
+
+    /// Begin a menu. Returns `None` if the menu is closed.
+    pub fn begin_menu(&self, label: &str, enabled: bool) -> Option<MenuToken<'_>> {
+        let (label, label_end) = str_range(label);
+        let open =
+            unsafe { ffi::imgui_begin_menu(label, label_end, if enabled { 1 } else { 0 }) != 0 };
+        open.then(|| MenuToken::new(self))
+    }
+
+    /// Run `f` inside a menu if it is open.
+    pub fn menu<R, F: FnOnce() -> R>(&self, label: &str, enabled: bool, f: F) -> Option<R> {
+        self.begin_menu(label, enabled).map(|_token| f())
+    }
+
+    /// Menu item. Returns true if activated.
+    pub fn menu_item(&self, label: &str, shortcut: Option<&str>, selected: bool, enabled: bool) -> bool {
+        let (label, label_end) = str_range(label);
+        let (shortcut, shortcut_end) = shortcut.map_or((ptr::null(), ptr::null()), str_range);
+        unsafe {
+            ffi::imgui_menu_item(
+                label,
+                label_end,
+                shortcut,
+                shortcut_end,
+                if selected { 1 } else { 0 },
+                if enabled { 1 } else { 0 },
+            ) != 0
+        }
+    }
+
+    // ---- Tooltips ----
+
+    /// Set a tooltip for the previous item.
+    pub fn set_tooltip(&self, text: &str) {
+        let (text, text_end) = str_range(text);
+        unsafe { ffi::imgui_set_tooltip(text, text_end) };
+    }
+
+    /// Set a tooltip for the previous item, failing if the text contains a NUL byte.
+    pub fn try_set_tooltip(&self, text: &str) -> Result<(), ImGuiError> {
+        check_nul(text)?;
+        self.set_tooltip(text);
+        Ok(())
+    }
+
+    /// Begin a tooltip. Returns `None` if the tooltip is not shown.
+    pub fn begin_tooltip(&self) -> Option<TooltipToken<'_>> {
+        let open = unsafe { ffi::imgui_begin_tooltip() != 0 };
+        open.then(|| TooltipToken::new(self))
+    }
+
+    /// Run `f` inside a tooltip.
+    pub fn tooltip<R, F: FnOnce() -> R>(&self, f: F) -> Option<R> {
+        self.begin_tooltip().map(|_token| f())
+    }
+
+    // ---- Popups ----
+
+    /// Begin a popup. Returns `None` if the popup is not open.
+    pub fn begin_popup(&self, str_id: &str, flags: WindowFlags) -> Option<PopupToken<'_>> {
+        let (str_id, str_id_end) = str_range(str_id);
+        let flags = flags.bits();
+        let open = unsafe { ffi::imgui_begin_popup(str_id, str_id_end, flags) != 0 };
+        open.then(|| PopupToken::new(self))
+    }
+
+    /// Run `f` inside a popup if it is open.
+    pub fn popup<R, F: FnOnce() -> R>(
+        &self,
+        str_id: &str,
+        flags: WindowFlags,
+        f: F,
+    ) -> Option<R> {
+        self.begin_popup(str_id, flags).map(|_token| f())
+    }
+
+    /// Begin a modal popup. Returns `None` if the popup is not open.
+    pub fn begin_popup_modal(
+        &self,
+        name: &str,
+        open: Option<&mut bool>,
+        flags: WindowFlags,
+    ) -> Option<PopupToken<'_>> {
+        let (name, name_end) = str_range(name);
+        let flags = flags.bits();
+        let visible = unsafe {
+            match open {
+                Some(open_ref) => {
+                    let mut open_int = if *open_ref { 1 } else { 0 };
+                    let result = ffi::imgui_begin_popup_modal(name, name_end, &mut open_int, flags);
+                    *open_ref = open_int != 0;
+                    result != 0
+                }
+                None => ffi::imgui_begin_popup_modal(name, name_end, ptr::null_mut(), flags) != 0,
+            }
+        };
+        visible.then(|| PopupToken::new(self))
+    }
+
+    /// Run `f` inside a modal popup if it is open.
+    pub fn popup_modal<R, F: FnOnce() -> R>(
+        &self,
+        name: &str,
+        open: Option<&mut bool>,
+        flags: WindowFlags,
+        f: F,
+    ) -> Option<R> {
+        self.begin_popup_modal(name, open, flags).map(|_token| f())
+    }
+
+    /// Open a popup.
+    pub fn open_popup(&self, str_id: &str) {
+        let (str_id, str_id_end) = str_range(str_id);
+        unsafe { ffi::imgui_open_popup(str_id, str_id_end) };
+    }
+
+    /// Close the current popup.
+    pub fn close_current_popup(&self) {
+        unsafe { ffi::imgui_close_current_popup() };
+    }
+
+    // ---- Tables ----
+
+    /// Begin a table. Returns `None` if the table is not visible.
+    pub fn begin_table(
+        &self,
+        str_id: &str,
+        columns: i32,
+        flags: TableFlags,
+    ) -> Option<TableToken<'_>> {
+        let (str_id, str_id_end) = str_range(str_id);
+        let flags = flags.bits();
+        let visible = unsafe { ffi::imgui_begin_table(str_id, str_id_end, columns, flags) != 0 };
+        visible.then(|| TableToken::new(self))
+    }
+
+    /// Run `f` inside a table if it is visible.
+    pub fn table<R, F: FnOnce() -> R>(
+        &self,
+        str_id: &str,
+        columns: i32,
+        flags: TableFlags,
+        f: F,
+    ) -> Option<R> {
+        self.begin_table(str_id, columns, flags).map(|_token| f())
+    }
+
+    /// Move to the next row in a table.
+    pub fn table_next_row(&self) {
+        unsafe { ffi::imgui_table_next_row() };
+    }
+
+    /// Move to the next column in a table.
+    pub fn table_next_column(&self) -> bool {
+        unsafe { ffi::imgui_table_next_column() != 0 }
+    }
+
+    /// Set the current column index.
+    pub fn table_set_column_index(&self, column: i32) -> bool {
+        unsafe { ffi::imgui_table_set_column_index(column) != 0 }
+    }
+
+    /// Setup a column (call before first row).
+    pub fn table_setup_column(&self, label: &str, flags: TableColumnFlags, init_width: f32) {
+        let (label, label_end) = str_range(label);
+        let flags = flags.bits();
+        unsafe { ffi::imgui_table_setup_column(label, label_end, flags, init_width) };
+    }
+
+    /// Display column headers row.
+    pub fn table_headers_row(&self) {
+        unsafe { ffi::imgui_table_headers_row() };
+    }
+
+    // ---- Columns (legacy) ----
+
+    /// Split the rest of the window into `count` columns; `count = 1` ends
+    /// the column set. Prefer tables in new code.
+    pub fn columns(&self, count: i32, id: &str, border: bool) {
+        let (id, id_end) = str_range(id);
+        unsafe { ffi::imgui_columns(count, id, id_end, if border { 1 } else { 0 }) };
+    }
+
+    /// Move to the next legacy column.
+    pub fn next_column(&self) {
+        unsafe { ffi::imgui_next_column() };
+    }
+
+    // ---- Style ----
+
+    /// Push a style color. The color is popped when the returned token is dropped.
+    pub fn push_style_color(&self, idx: StyleColor, r: f32, g: f32, b: f32, a: f32) -> StyleColorToken<'_> {
+        unsafe { ffi::imgui_push_style_color(idx as i32, r, g, b, a) };
+        StyleColorToken::new(self)
+    }
+
+    /// Run `f` with a style color pushed.
+    pub fn with_style_color<R, F: FnOnce() -> R>(&self, idx: StyleColor, color: [f32; 4], f: F) -> R {
+        let [r, g, b, a] = color;
+        let _token = self.push_style_color(idx, r, g, b, a);
+        f()
+    }
+
+    /// Push a style variable. The variable is popped when the returned token is dropped.
+    ///
+    /// ```ignore
+    /// let _rounding = ui.push_style_var(StyleVar::FrameRounding(4.0));
+    /// let _padding = ui.push_style_var(StyleVar::FramePadding([8.0, 4.0]));
+    /// ```
+    pub fn push_style_var(&self, var: StyleVar) -> StyleVarToken<'_> {
+        match var.split() {
+            (idx, StyleVarValue::Float(v)) => unsafe { ffi::imgui_push_style_var_float(idx, v) },
+            (idx, StyleVarValue::Vec2([x, y])) => unsafe { ffi::imgui_push_style_var_vec2(idx, x, y) },
+        }
+        StyleVarToken::new(self)
+    }
+
+    /// Run `f` with a style variable pushed.
+    pub fn with_style_var<R, F: FnOnce() -> R>(&self, var: StyleVar, f: F) -> R {
+        let _token = self.push_style_var(var);
+        f()
+    }
+
+    // ---- Fonts ----
+
+    /// Push a font from the atlas. The font is popped when the returned token is dropped.
+    pub fn push_font(&self, font: FontId) -> FontToken<'_> {
+        unsafe { ffi::imgui_push_font(font.0) };
+        FontToken::new(self)
+    }
+
+    /// Run `f` with a font pushed.
+    pub fn with_font<R, F: FnOnce() -> R>(&self, font: FontId, f: F) -> R {
+        let _token = self.push_font(font);
+        f()
+    }
+
+    // ---- ID Stack ----
+
+    /// Push an integer ID. The ID is popped when the returned token is dropped.
+    pub fn push_id_int(&self, id: i32) -> IdToken<'_> {
+        unsafe { ffi::imgui_push_id_int(id) };
+        IdToken::new(self)
+    }
+
+    /// Push a string ID. The ID is popped when the returned token is dropped.
+    pub fn push_id(&self, id: &str) -> IdToken<'_> {
+        let (id, id_end) = str_range(id);
+        unsafe { ffi::imgui_push_id_str(id, id_end) };
+        IdToken::new(self)
+    }
+
+    /// Run `f` with a string ID pushed.
+    pub fn with_id<R, F: FnOnce() -> R>(&self, id: &str, f: F) -> R {
+        let _token = self.push_id(id);
+        f()
+    }
+
+    /// Run `f` with an integer ID pushed.
+    pub fn with_id_int<R, F: FnOnce() -> R>(&self, id: i32, f: F) -> R {
+        let _token = self.push_id_int(id);
+        f()
+    }
+
+    // ---- Item Queries ----
+
+    /// Returns true if the last item is hovered.
+    pub fn is_item_hovered(&self) -> bool {
+        unsafe { ffi::imgui_is_item_hovered() != 0 }
+    }
+
+    /// Returns true if the last item was clicked with `button`.
+    pub fn is_item_clicked(&self, button: MouseButton) -> bool {
+        unsafe { ffi::imgui_is_item_clicked(button as i32) != 0 }
+    }
+
+    /// Returns true if the last item is active.
+    pub fn is_item_active(&self) -> bool {
+        unsafe { ffi::imgui_is_item_active() != 0 }
+    }
+
+    // ---- Cursor and Mouse ----
+
+    /// Space left in the current window or child, from the cursor to the
+    /// bottom-right corner of the content region.
+    pub fn content_region_avail(&self) -> [f32; 2] {
+        let mut size = [0.0; 2];
+        unsafe { ffi::imgui_get_content_region_avail(size.as_mut_ptr()) };
+        size
+    }
+
+    /// Position of the next item in screen coordinates.
+    pub fn cursor_screen_pos(&self) -> [f32; 2] {
+        let mut pos = [0.0; 2];
+        unsafe { ffi::imgui_get_cursor_screen_pos(pos.as_mut_ptr()) };
+        pos
+    }
+
+    /// Mouse position in screen coordinates.
+    pub fn mouse_pos(&self) -> [f32; 2] {
+        let mut pos = [0.0; 2];
+        unsafe { ffi::imgui_get_mouse_pos(pos.as_mut_ptr()) };
+        pos
+    }
+
+    // ---- Demo ----
+
+    /// Show the ImGui demo window.
+    pub fn show_demo_window(&self, open: Option<&mut bool>) {
+        unsafe {
+            match open {
+                Some(open_ref) => {
+                    let mut open_int = if *open_ref { 1 } else { 0 };
+                    ffi::imgui_show_demo_window(&mut open_int);
+                    *open_ref = open_int != 0;
+                }
+                None => ffi::imgui_show_demo_window(ptr::null_mut()),
+            }
+        }
+    }
+}
+
+/// Raw `i32` versions of the methods that took them before the typed flags.
+///
+/// Unknown flag bits are dropped.
+impl Ui<'_> {
+    #[deprecated(since = "0.2.0", note = "use `begin()` with `WindowFlags`")]
+    pub fn begin_raw(&self, name: &str, open: Option<&mut bool>, flags: i32) -> Option<WindowToken<'_>> {
+        self.begin(name, open, WindowFlags::from_bits_truncate(flags))
+    }
+
+    /// # Panics
+    /// Panics if `cond` is not one of the `cond` constants.
+    #[deprecated(since = "0.2.0", note = "use `set_next_window_pos()` with `Cond`")]
+    pub fn set_next_window_pos_raw(&self, x: f32, y: f32, cond: i32) {
+        self.set_next_window_pos(x, y, raw_cond(cond));
+    }
+
+    /// # Panics
+    /// Panics if `cond` is not one of the `cond` constants.
+    #[deprecated(since = "0.2.0", note = "use `set_next_window_size()` with `Cond`")]
+    pub fn set_next_window_size_raw(&self, width: f32, height: f32, cond: i32) {
+        self.set_next_window_size(width, height, raw_cond(cond));
+    }
+
+    /// Returns false for buttons outside `0..5`, which ImGui does not track.
+    #[deprecated(since = "0.2.0", note = "use `is_item_clicked()` with `MouseButton`")]
+    pub fn is_item_clicked_raw(&self, mouse_button: i32) -> bool {
+        MouseButton::from_index(mouse_button).is_some_and(|button| self.is_item_clicked(button))
+    }
+
+    #[deprecated(since = "0.2.0", note = "use `begin_combo()` with `ComboFlags`")]
+    pub fn begin_combo_raw(&self, label: &str, preview: &str, flags: i32) -> Option<ComboToken<'_>> {
+        self.begin_combo(label, preview, ComboFlags::from_bits_truncate(flags))
+    }
+
+    #[deprecated(since = "0.2.0", note = "use `combo()` with `ComboFlags`")]
+    pub fn combo_raw<R, F: FnOnce() -> R>(&self, label: &str, preview: &str, flags: i32, f: F) -> Option<R> {
+        self.combo(label, preview, ComboFlags::from_bits_truncate(flags), f)
+    }
+
+    #[deprecated(since = "0.2.0", note = "use `selectable()` with `SelectableFlags`")]
+    pub fn selectable_raw(&self, label: &str, selected: bool, flags: i32) -> bool {
+        self.selectable(label, selected, SelectableFlags::from_bits_truncate(flags))
+    }
+
+    #[deprecated(since = "0.2.0", note = "use `try_selectable()` with `SelectableFlags`")]
+    pub fn try_selectable_raw(&self, label: &str, selected: bool, flags: i32) -> Result<bool, ImGuiError> {
+        self.try_selectable(label, selected, SelectableFlags::from_bits_truncate(flags))
+    }
+
+    #[deprecated(since = "0.2.0", note = "use `begin_popup()` with `WindowFlags`")]
+    pub fn begin_popup_raw(&self, str_id: &str, flags: i32) -> Option<PopupToken<'_>> {
+        self.begin_popup(str_id, WindowFlags::from_bits_truncate(flags))
+    }
+
+    #[deprecated(since = "0.2.0", note = "use `popup()` with `WindowFlags`")]
+    pub fn popup_raw<R, F: FnOnce() -> R>(&self, str_id: &str, flags: i32, f: F) -> Option<R> {
+        self.popup(str_id, WindowFlags::from_bits_truncate(flags), f)
+    }
+
+    #[deprecated(since = "0.2.0", note = "use `begin_popup_modal()` with `WindowFlags`")]
+    pub fn begin_popup_modal_raw(&self, name: &str, open: Option<&mut bool>, flags: i32) -> Option<PopupToken<'_>> {
+        self.begin_popup_modal(name, open, WindowFlags::from_bits_truncate(flags))
+    }
+
+    #[deprecated(since = "0.2.0", note = "use `popup_modal()` with `WindowFlags`")]
+    pub fn popup_modal_raw<R, F: FnOnce() -> R>(
+        &self,
+        name: &str,
+        open: Option<&mut bool>,
+        flags: i32,
+        f: F,
+    ) -> Option<R> {
+        self.popup_modal(name, open, WindowFlags::from_bits_truncate(flags), f)
+    }
+
+    #[deprecated(since = "0.2.0", note = "use `begin_table()` with `TableFlags`")]
+    pub fn begin_table_raw(&self, str_id: &str, columns: i32, flags: i32) -> Option<TableToken<'_>> {
+        self.begin_table(str_id, columns, TableFlags::from_bits_truncate(flags))
+    }
+
+    #[deprecated(since = "0.2.0", note = "use `table()` with `TableFlags`")]
+    pub fn table_raw<R, F: FnOnce() -> R>(&self, str_id: &str, columns: i32, flags: i32, f: F) -> Option<R> {
+        self.table(str_id, columns, TableFlags::from_bits_truncate(flags), f)
+    }
+
+    #[deprecated(since = "0.2.0", note = "use `table_setup_column()` with `TableColumnFlags`")]
+    pub fn table_setup_column_raw(&self, label: &str, flags: i32, init_width: f32) {
+        self.table_setup_column(label, TableColumnFlags::from_bits_truncate(flags), init_width);
+    }
+}
+
+/// Convert a deprecated `cond` constant.
+pub(crate) fn raw_cond(cond: i32) -> Cond {
+    Cond::try_from(cond).unwrap_or_else(|err| panic!("{}", err))
+}
+
+impl Drop for Ui<'_> {
+    fn drop(&mut self) {
+        unsafe { ffi::imgui_end_frame() };
+        close_frame();
+    }
+}
@@ -0,0 +1,141 @@
+//! A panel showing wilhelm_renderer shapes inside an ImGui window.
+
+use wilhelm_renderer::core::{Renderable, Renderer};
+
+use crate::{color_to_rgba, ffi, ImGuiError, RenderTarget, TextureId, Ui};
+
+/// Renders a scene into an offscreen target sized to the content region of
+/// the current window and shows it there.
+///
+/// wilhelm_renderer projects shapes onto the main window, so the panel
+/// shows the scene at one window coordinate per panel pixel, with the
+/// scene's origin at the panel's top-left corner. Keep the shapes out of
+/// the `App` so they are not also drawn behind the UI:
+///
+/// ```ignore
+/// let mut viewport = Viewport::new()?;
+/// app.on_render(move |renderer| {
+///     let ui = imgui.new_frame();
+///     ui.window("Scene").build(|| {
+///         viewport.show(&ui, renderer, &mut shapes);
+///         if let Some([x, y]) = viewport.mouse_scene_pos(&ui) {
+///             ui.set_tooltip(&format!("{x:.0}, {y:.0}"));
+///         }
+///     });
+///     ui.render();
+/// });
+/// ```
+#[derive(Debug)]
+pub struct Viewport {
+    target: RenderTarget,
+    clear_color: Option<[f32; 4]>,
+    // Screen rectangle of the image shown by the last `show()`
+    origin: [f32; 2],
+    size: [f32; 2],
+    hovered: bool,
+}
+
+impl Viewport {
+    /// Create the offscreen target. Needs the window's GL context to be current.
+    ///
+    /// # Errors
+    /// Returns `ImGuiError::RenderTarget` if the framebuffer cannot be created.
+    pub fn new() -> Result<Self, ImGuiError> {
+        Ok(Self {
+            target: RenderTarget::new(1, 1)?,
+            clear_color: None,
+            origin: [0.0; 2],
+            size: [0.0; 2],
+            hovered: false,
+        })
+    }
+
+    /// Background of the panel. `None`, the default, uses the window's
+    /// background color.
+    pub fn set_clear_color(&mut self, color: Option<[f32; 4]>) {
+        self.clear_color = color;
+    }
+
+    /// Render `shapes` and show them in the remaining space of the current
+    /// window. Returns true if the panel is hovered.
+    pub fn show<R: Renderable>(&mut self, ui: &Ui, renderer: &Renderer, shapes: &mut [R]) -> bool {
+        let avail = ui.content_region_avail();
+        self.origin = ui.cursor_screen_pos();
+        self.size = [avail[0].max(0.0).floor(), avail[1].max(0.0).floor()];
+        self.hovered = false;
+        if self.size[0] < 1.0 || self.size[1] < 1.0 {
+            return false;
+        }
+
+        let scale = framebuffer_scale();
+        let width = (self.size[0] * scale[0]).round().max(1.0) as u32;
+        let height = (self.size[1] * scale[1]).round().max(1.0) as u32;
+        self.target.resize(width, height);
+
+        // Map the window-sized scene projection so that its top-left corner
+        // lands on the target's top-left; OpenGL's y axis points up.
+        let (scene_width, scene_height) = renderer.window_handle.size();
+        let scene_width = (scene_width as f32 * scale[0]).round() as i32;
+        let scene_height = (scene_height as f32 * scale[1]).round() as i32;
+        let viewport = [0, height as i32 - scene_height, scene_width, scene_height];
+
+        let clear = self
+            .clear_color
+            .unwrap_or_else(|| color_to_rgba(renderer.window_handle.background_color()));
+        self.target.draw_with_viewport(viewport, || unsafe {
+            ffi::imgui_render_target_clear(clear[0], clear[1], clear[2], 1.0);
+            // Blending would otherwise make translucent shapes see-through in the panel
+            ffi::imgui_render_target_lock_alpha(1);
+            for shape in shapes.iter_mut() {
+                shape.render(renderer);
+            }
+        });
+
+        ui.image(self.target.texture(), self.size, [0.0, 1.0], [1.0, 0.0], [1.0; 4], [0.0; 4]);
+        self.hovered = ui.is_item_hovered();
+        self.hovered
+    }
+
+    /// Returns true if the panel was hovered during the last `show()`.
+    pub fn is_hovered(&self) -> bool {
+        self.hovered
+    }
+
+    /// Size of the panel in the last `show()`, in window coordinates.
+    pub fn size(&self) -> [f32; 2] {
+        self.size
+    }
+
+    /// The texture holding the last rendered scene.
+    pub fn texture(&self) -> TextureId {
+        self.target.texture()
+    }
+
+    /// Convert a screen position to scene coordinates, or `None` if it is
+    /// outside the panel.
+    pub fn to_scene(&self, screen_pos: [f32; 2]) -> Option<[f32; 2]> {
+        let x = screen_pos[0] - self.origin[0];
+        let y = screen_pos[1] - self.origin[1];
+        if (0.0..self.size[0]).contains(&x) && (0.0..self.size[1]).contains(&y) {
+            Some([x, y])
+        } else {
+            None
+        }
+    }
+
+    /// The mouse position in scene coordinates while the panel is hovered.
+    pub fn mouse_scene_pos(&self, ui: &Ui) -> Option<[f32; 2]> {
+        if self.hovered {
+            self.to_scene(ui.mouse_pos())
+        } else {
+            None
+        }
+    }
+}
+
+/// Framebuffer pixels per window coordinate.
+fn framebuffer_scale() -> [f32; 2] {
+    let mut scale = [1.0; 2];
+    unsafe { ffi::imgui_io_display_framebuffer_scale(scale.as_mut_ptr()) };
+    scale.map(|s| if s.is_finite() && s > 0.0 { s } else { 1.0 })
+}
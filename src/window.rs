@@ -0,0 +1,96 @@
+//! Builder for ImGui windows.
+
+use crate::ui::raw_cond;
+use crate::{Cond, Ui, WindowFlags, WindowToken};
+
+/// Builder returned by `Ui::window()`.
+///
+/// ```ignore
+/// ui.window("Debug")
+///     .size(300.0, 200.0, Cond::FirstUseEver)
+///     .build(|| {
+///         ui.text("Hello, ImGui!");
+///     });
+/// ```
+#[must_use = "call `begin()` or `build()` to submit the window"]
+pub struct WindowBuilder<'ui, 'a> {
+    ui: &'ui Ui<'ui>,
+    name: &'a str,
+    opened: Option<&'a mut bool>,
+    flags: WindowFlags,
+    position: Option<(f32, f32, Cond)>,
+    size: Option<(f32, f32, Cond)>,
+}
+
+impl<'ui, 'a> WindowBuilder<'ui, 'a> {
+    pub(crate) fn new(ui: &'ui Ui<'ui>, name: &'a str) -> Self {
+        Self {
+            ui,
+            name,
+            opened: None,
+            flags: WindowFlags::empty(),
+            position: None,
+            size: None,
+        }
+    }
+
+    /// Show a close button that clears `opened` when clicked.
+    pub fn opened(mut self, opened: &'a mut bool) -> Self {
+        self.opened = Some(opened);
+        self
+    }
+
+    /// Window flags.
+    pub fn flags(mut self, flags: WindowFlags) -> Self {
+        self.flags = flags;
+        self
+    }
+
+    /// Set the window position, see `set_next_window_pos()`.
+    pub fn position(mut self, x: f32, y: f32, cond: Cond) -> Self {
+        self.position = Some((x, y, cond));
+        self
+    }
+
+    /// Set the window size, see `set_next_window_size()`.
+    pub fn size(mut self, width: f32, height: f32, cond: Cond) -> Self {
+        self.size = Some((width, height, cond));
+        self
+    }
+
+    /// Window flags as a raw `i32`. Unknown bits are dropped.
+    #[deprecated(since = "0.2.0", note = "use `flags()` with `WindowFlags`")]
+    pub fn flags_raw(self, flags: i32) -> Self {
+        self.flags(WindowFlags::from_bits_truncate(flags))
+    }
+
+    /// # Panics
+    /// Panics if `cond` is not one of the `cond` constants.
+    #[deprecated(since = "0.2.0", note = "use `position()` with `Cond`")]
+    pub fn position_raw(self, x: f32, y: f32, cond: i32) -> Self {
+        self.position(x, y, raw_cond(cond))
+    }
+
+    /// # Panics
+    /// Panics if `cond` is not one of the `cond` constants.
+    #[deprecated(since = "0.2.0", note = "use `size()` with `Cond`")]
+    pub fn size_raw(self, width: f32, height: f32, cond: i32) -> Self {
+        self.size(width, height, raw_cond(cond))
+    }
+
+    /// Begin the window. Returns `None` if the window is collapsed or clipped.
+    pub fn begin(self) -> Option<WindowToken<'ui>> {
+        if let Some((x, y, cond)) = self.position {
+            self.ui.set_next_window_pos(x, y, cond);
+        }
+        if let Some((width, height, cond)) = self.size {
+            self.ui.set_next_window_size(width, height, cond);
+        }
+        self.ui.begin(self.name, self.opened, self.flags)
+    }
+
+    /// Begin the window, run `f` if it is visible, then end it.
+    pub fn build<R, F: FnOnce() -> R>(self, f: F) -> Option<R> {
+        self.begin().map(|_token| f())
+    }
+}
@@ -0,0 +1,265 @@
+//! Frames driven through headless contexts.
+//!
+//! Dear ImGui's current context is global, so every test holds `LOCK` while
+//! it has a context alive.
+
+use std::sync::{Mutex, MutexGuard};
+
+use wilhelm_renderer_imgui::{
+    AngleSlider, ColorEditFlags, Cond, ImGui, ImGuiError, InputTextFlags, MouseButton, Slider, SliderFlags, VSlider,
+    WindowFlags,
+};
+
+static LOCK: Mutex<()> = Mutex::new(());
+
+fn lock() -> MutexGuard<'static, ()> {
+    // A failed test poisons the lock but leaves no context behind
+    LOCK.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+/// Run one frame with an "OK" button at the top-left of the display and
+/// return whether it was clicked.
+fn button_frame(imgui: &mut ImGui) -> bool {
+    let ui = imgui.new_frame();
+    let clicked = ui
+        .window("Buttons")
+        .position(0.0, 0.0, Cond::Always)
+        .size(200.0, 100.0, Cond::Always)
+        .flags(WindowFlags::NO_TITLE_BAR | WindowFlags::NO_MOVE | WindowFlags::NO_RESIZE)
+        .build(|| ui.button("OK"))
+        .unwrap_or(false);
+    ui.render();
+    clicked
+}
+
+#[test]
+fn click_registers_on_a_button() {
+    let _lock = lock();
+    let mut imgui = ImGui::new_headless([800.0, 600.0]);
+    imgui.set_delta_time(1.0 / 30.0);
+    imgui.set_display_size([640.0, 480.0]);
+
+    // The first frame lays the window out. ImGui's input queue spreads a move
+    // and a press queued together over two frames, so each gets its own.
+    assert!(!button_frame(&mut imgui));
+    imgui.add_mouse_pos_event([20.0, 18.0]);
+    assert!(!button_frame(&mut imgui));
+    imgui.add_mouse_button_event(MouseButton::Left, true);
+    assert!(!button_frame(&mut imgui));
+    imgui.add_mouse_button_event(MouseButton::Left, false);
+    assert!(button_frame(&mut imgui));
+    assert!(!button_frame(&mut imgui));
+
+    assert_eq!(imgui.draw_data().unwrap().display_size, [640.0, 480.0]);
+}
+
+#[test]
+fn click_outside_the_button_is_ignored() {
+    let _lock = lock();
+    let mut imgui = ImGui::new_headless([800.0, 600.0]);
+
+    assert!(!button_frame(&mut imgui));
+    imgui.add_mouse_pos_event([150.0, 80.0]);
+    assert!(!button_frame(&mut imgui));
+    imgui.add_mouse_button_event(MouseButton::Left, true);
+    assert!(!button_frame(&mut imgui));
+    imgui.add_mouse_button_event(MouseButton::Left, false);
+    assert!(!button_frame(&mut imgui));
+}
+
+#[test]
+fn item_clicks_report_their_mouse_button() {
+    let _lock = lock();
+    let mut imgui = ImGui::new_headless([800.0, 600.0]);
+    let frame = |imgui: &mut ImGui| {
+        let ui = imgui.new_frame();
+        let mut clicked = [false; 2];
+        ui.window("Buttons").position(0.0, 0.0, Cond::Always).build(|| {
+            ui.button("OK");
+            clicked = [ui.is_item_clicked(MouseButton::Left), ui.is_item_clicked(MouseButton::Right)];
+            #[allow(deprecated)]
+            let out_of_range = ui.is_item_clicked_raw(5);
+            assert!(!out_of_range);
+        });
+        ui.render();
+        clicked
+    };
+
+    frame(&mut imgui);
+    imgui.add_mouse_pos_event([20.0, 30.0]);
+    frame(&mut imgui);
+    imgui.add_mouse_button_event(MouseButton::Right, true);
+    assert_eq!(frame(&mut imgui), [false, true]);
+}
+
+#[test]
+fn render_produces_draw_data() {
+    let _lock = lock();
+    let mut imgui = ImGui::new_headless([800.0, 600.0]);
+    assert!(imgui.draw_data().is_none());
+
+    button_frame(&mut imgui);
+
+    let draw_data = imgui.draw_data().unwrap();
+    assert_eq!(draw_data.display_size, [800.0, 600.0]);
+    assert_eq!(draw_data.framebuffer_size(), [800, 600]);
+    assert!(draw_data.total_vtx_count() > 0);
+    assert!(draw_data.total_idx_count() > 0);
+    let list = &draw_data.draw_lists()[0];
+    assert!(!list.commands().is_empty());
+    assert_eq!(list.triangles(&list.commands()[0]).count() * 3, list.commands()[0].elem_count);
+}
+
+#[test]
+fn contexts_created_and_dropped_in_sequence() {
+    let _lock = lock();
+    for _ in 0..2 {
+        let mut imgui = ImGui::new_headless([320.0, 240.0]);
+        button_frame(&mut imgui);
+        assert!(imgui.draw_data().unwrap().total_vtx_count() > 0);
+    }
+}
+
+#[test]
+fn interleaved_contexts_keep_their_own_state() {
+    let _lock = lock();
+    let mut first = ImGui::new_headless([320.0, 240.0]);
+    let mut second = ImGui::new_headless([640.0, 480.0]);
+
+    button_frame(&mut first);
+    button_frame(&mut second);
+    assert_eq!(first.draw_data().unwrap().display_size, [320.0, 240.0]);
+    assert_eq!(second.draw_data().unwrap().display_size, [640.0, 480.0]);
+
+    drop(first);
+    button_frame(&mut second);
+    assert_eq!(second.draw_data().unwrap().display_size, [640.0, 480.0]);
+}
+
+#[test]
+fn a_frame_can_open_once_the_other_context_is_done() {
+    let _lock = lock();
+    let mut first = ImGui::new_headless([320.0, 240.0]);
+    let mut second = ImGui::new_headless([640.0, 480.0]);
+
+    // Dropping the `Ui` without rendering also ends the frame
+    drop(first.new_frame());
+    button_frame(&mut second);
+    button_frame(&mut first);
+    assert_eq!(second.draw_data().unwrap().display_size, [640.0, 480.0]);
+}
+
+#[test]
+#[should_panic(expected = "still open")]
+fn only_one_frame_may_be_open_at_a_time() {
+    let _lock = lock();
+    let mut first = ImGui::new_headless([320.0, 240.0]);
+    let mut second = ImGui::new_headless([640.0, 480.0]);
+
+    let _ui = first.new_frame();
+    let _ = second.new_frame();
+}
+
+#[test]
+fn sliders_beyond_half_the_type_limits_are_clamped_or_rejected() {
+    let _lock = lock();
+    let mut imgui = ImGui::new_headless([320.0, 240.0]);
+    let ui = imgui.new_frame();
+    let (mut wide, mut int, mut float) = (5u64, 0i32, 0.0f64);
+
+    ui.slider_int("int", &mut int, i32::MIN, i32::MAX);
+    ui.slider_scalar("u64", &mut wide, 0..=u64::MAX, None, Default::default());
+    ui.v_slider_scalar("f64", [20.0, 80.0], &mut float, -f64::MAX..=f64::NAN, None, Default::default());
+    assert!(Slider::new("ok", 0..=u64::MAX / 2).try_build(&ui, &mut wide).is_ok());
+    let rejected = Slider::new("u64", 0..=u64::MAX).try_build(&ui, &mut wide);
+    assert!(matches!(rejected, Err(ImGuiError::InvalidRange { .. })));
+    let rejected = VSlider::new("i32", [20.0, 80.0], i32::MIN..=0).try_build(&ui, &mut int);
+    assert!(matches!(rejected, Err(ImGuiError::InvalidRange { .. })));
+    ui.render();
+    assert_eq!(wide, 5);
+}
+
+#[test]
+fn sliders_ignore_or_reject_wrap_around() {
+    let _lock = lock();
+    let mut imgui = ImGui::new_headless([320.0, 240.0]);
+    let ui = imgui.new_frame();
+    let (mut value, mut angle) = (0.5f32, 0.0f32);
+
+    ui.slider_scalar("slider", &mut value, 0.0..=1.0, None, SliderFlags::WRAP_AROUND);
+    VSlider::new("vslider", [20.0, 80.0], 0.0..=1.0)
+        .flags(SliderFlags::WRAP_AROUND)
+        .build(&ui, &mut value);
+    AngleSlider::new("angle").flags(SliderFlags::WRAP_AROUND).build(&ui, &mut angle);
+    let rejected = Slider::new("slider", 0.0..=1.0)
+        .flags(SliderFlags::WRAP_AROUND | SliderFlags::ALWAYS_CLAMP)
+        .try_build(&ui, &mut value);
+    assert!(matches!(rejected, Err(ImGuiError::InvalidFlags { .. })));
+    let rejected = AngleSlider::new("angle").flags(SliderFlags::WRAP_AROUND).try_build(&ui, &mut angle);
+    assert!(matches!(rejected, Err(ImGuiError::InvalidFlags { .. })));
+    // Drags support it
+    ui.drag_scalar("drag", &mut value, 0.01, 0.0..=1.0, None, SliderFlags::WRAP_AROUND);
+    ui.render();
+}
+
+#[test]
+fn color_flags_with_several_options_of_a_group_are_ignored() {
+    let _lock = lock();
+    let mut imgui = ImGui::new_headless([320.0, 240.0]);
+    let display = ColorEditFlags::DISPLAY_RGB | ColorEditFlags::DISPLAY_HEX;
+    let picker = ColorEditFlags::PICKER_HUE_BAR | ColorEditFlags::PICKER_HUE_WHEEL;
+    let data = ColorEditFlags::UINT8 | ColorEditFlags::FLOAT;
+    let input = ColorEditFlags::INPUT_RGB | ColorEditFlags::INPUT_HSV;
+    for flags in [display, picker, data, input] {
+        assert!(matches!(flags.validate(), Err(ImGuiError::InvalidFlags { .. })));
+    }
+    assert_eq!((ColorEditFlags::DISPLAY_HSV | ColorEditFlags::FLOAT | ColorEditFlags::NO_ALPHA).validate(), Ok(()));
+
+    let ui = imgui.new_frame();
+    let mut color = [0.2, 0.4, 0.6, 1.0];
+    ui.set_color_edit_options(display | data | picker | input);
+    ui.color_edit4_with_flags("edit", &mut color, display | input);
+    ui.color_picker4("picker", &mut color, picker | input | ColorEditFlags::NO_ALPHA, None);
+    ui.color_button("button", color, display | input, [0.0, 0.0]);
+    ui.render();
+}
+
+#[test]
+fn multiline_input_ignores_single_line_flags() {
+    let _lock = lock();
+    let mut imgui = ImGui::new_headless([320.0, 240.0]);
+    let mut text = String::from("first line\nsecond line");
+
+    let ui = imgui.new_frame();
+    let flags = InputTextFlags::ELIDE_LEFT | InputTextFlags::CALLBACK_HISTORY;
+    assert!(!ui.input_text_multiline("notes", &mut text, [0.0, 0.0], flags));
+    ui.render();
+    assert_eq!(text, "first line\nsecond line");
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn theme_watcher_keeps_the_style_when_the_file_is_out_of_range() {
+    use std::fs;
+    use std::time::{Duration, SystemTime};
+    use wilhelm_renderer_imgui::ThemeWatcher;
+
+    let _lock = lock();
+    let mut imgui = ImGui::new_headless([320.0, 240.0]);
+    let path = std::env::temp_dir().join("wilhelm_imgui_watcher_range_test.toml");
+    let mut watcher = ThemeWatcher::new(&path);
+
+    fs::write(&path, "alpha = 0.5\n").unwrap();
+    assert_eq!(watcher.poll(&mut imgui), Ok(true));
+
+    // Move the timestamp on so the rewrite counts as a change
+    fs::write(&path, "alpha = 1.5\n").unwrap();
+    let file = fs::File::options().write(true).open(&path).unwrap();
+    file.set_modified(SystemTime::now() + Duration::from_secs(60)).unwrap();
+    let polled = watcher.poll(&mut imgui);
+    let _ = fs::remove_file(&path);
+
+    assert!(matches!(polled, Err(ImGuiError::Theme { .. })));
+    assert_eq!(imgui.style().alpha, 0.5);
+    button_frame(&mut imgui);
+}